@@ -0,0 +1,69 @@
+//! Compares the speed of `OwnedLinkedList` and `LinkedList`; the unit tests of
+//! `owned_linked_list` check that they behave alike.
+//!
+//! ```text
+//! cargo run --release --example owned_parity
+//! ```
+//!
+//! Both lists run the same random mix of `push_back`/`pop_front`, then get summed through
+//! their iterators.
+
+use collections_test::data_structures::{
+    linked_list::{LinkedList, List},
+    owned_linked_list::OwnedLinkedList,
+};
+use std::time::{Duration, Instant};
+
+const OPS: usize = 1_000_000;
+
+/// Small xorshift generator, so runs are reproducible
+struct XorShift(u64);
+
+impl XorShift {
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+fn timed<R>(f: impl FnOnce() -> R) -> (R, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+fn main() {
+    let mut rng = XorShift(0x5eed);
+    let mut shared = LinkedList::new();
+    let mut owned = OwnedLinkedList::new();
+    let mut shared_time = Duration::default();
+    let mut owned_time = Duration::default();
+
+    for _ in 0..OPS {
+        let value = rng.next_u64();
+        // push twice as often as pop, so the lists grow
+        if value.is_multiple_of(3) {
+            shared_time += timed(|| shared.shift()).1;
+            owned_time += timed(|| owned.pop_front()).1;
+        } else {
+            shared_time += timed(|| shared.add_raw(value)).1;
+            owned_time += timed(|| owned.push_back(value)).1;
+        }
+    }
+    println!(
+        "{} push_back/pop_front: LinkedList {:.3?}, OwnedLinkedList {:.3?}",
+        OPS, shared_time, owned_time
+    );
+
+    let (expected, shared_time) = timed(|| shared.iter().map(|item| *item.borrow()).sum::<u64>());
+    let (sum, owned_time) = timed(|| owned.iter().sum::<u64>());
+    assert_eq!(sum, expected);
+    println!(
+        "iter over {} elements: LinkedList {:.3?}, OwnedLinkedList {:.3?}",
+        owned.size(),
+        shared_time,
+        owned_time
+    );
+}
@@ -0,0 +1,321 @@
+//! Random workload generator for the list implementations.
+//!
+//! Runs a configurable mix of operations against one of the lists, mirrors every
+//! operation on a `Vec` model, reports throughput and checks that the list and the
+//! model agree at the end, and that the elements are freed once both are dropped.
+//!
+//! ```text
+//! cargo run --release --example stress -- --impl linked_list2 --ops 200000 --size 1000 \
+//!     --seed 7 --mix add=4,insert=2,remove_at=2,remove=1,get=4,contains=1 --elements string
+//! ```
+//!
+//! To stress your own element type, implement `Generate` for it and add it to `main`.
+
+use collections_test::data_structures::{
+    array_list::ArrayList,
+    indexed_linked_list::IndexedLinkedList,
+    linked_list::{LinkedList, List},
+    linked_list2::LinkedList2,
+};
+use std::{
+    cell::RefCell,
+    env,
+    fmt::Debug,
+    process,
+    rc::{Rc, Weak},
+    time::Instant,
+};
+
+/// Small xorshift generator, so runs are reproducible from `--seed` alone
+struct XorShift(u64);
+
+impl XorShift {
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// a value in `0..bound`, or 0 if `bound` is 0
+    fn below(&mut self, bound: usize) -> usize {
+        if bound == 0 {
+            0
+        } else {
+            (self.next_u64() % bound as u64) as usize
+        }
+    }
+}
+
+/// Element types a workload can be run with
+trait Generate: Debug {
+    fn generate(rng: &mut XorShift) -> Self;
+}
+
+impl Generate for u64 {
+    fn generate(rng: &mut XorShift) -> Self {
+        rng.next_u64()
+    }
+}
+
+impl Generate for String {
+    fn generate(rng: &mut XorShift) -> Self {
+        format!("element-{}", rng.next_u64() % 10_000)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Add,
+    Insert,
+    RemoveAt,
+    Remove,
+    Get,
+    Contains,
+}
+
+struct Config {
+    implementation: String,
+    elements: String,
+    ops: usize,
+    size: usize,
+    seed: u64,
+    mix: Vec<(Op, usize)>,
+}
+
+impl Config {
+    fn from_args() -> Result<Self, String> {
+        let mut config = Config {
+            implementation: "linked_list".to_string(),
+            elements: "u64".to_string(),
+            ops: 100_000,
+            size: 1_000,
+            seed: 0x5eed,
+            mix: parse_mix("add=4,insert=2,remove_at=2,remove=1,get=4,contains=1")?,
+        };
+
+        let mut args = env::args().skip(1);
+        while let Some(flag) = args.next() {
+            let value = args
+                .next()
+                .ok_or_else(|| format!("missing value for {}", flag))?;
+            match flag.as_str() {
+                "--impl" => config.implementation = value,
+                "--elements" => config.elements = value,
+                "--ops" => config.ops = value.parse().map_err(|_| "--ops expects a number")?,
+                "--size" => config.size = value.parse().map_err(|_| "--size expects a number")?,
+                "--seed" => config.seed = value.parse().map_err(|_| "--seed expects a number")?,
+                "--mix" => config.mix = parse_mix(&value)?,
+                _ => return Err(format!("unknown flag {}", flag)),
+            }
+        }
+
+        if config.seed == 0 {
+            // xorshift never leaves 0
+            config.seed = 1;
+        }
+        Ok(config)
+    }
+}
+
+/// Parses `name=weight,...` into weighted operations
+fn parse_mix(mix: &str) -> Result<Vec<(Op, usize)>, String> {
+    let mut ops = Vec::new();
+    for entry in mix.split(',') {
+        let (name, weight) = entry
+            .split_once('=')
+            .ok_or_else(|| format!("expected name=weight, got {}", entry))?;
+        let op = match name {
+            "add" => Op::Add,
+            "insert" => Op::Insert,
+            "remove_at" => Op::RemoveAt,
+            "remove" => Op::Remove,
+            "get" => Op::Get,
+            "contains" => Op::Contains,
+            _ => return Err(format!("unknown operation {}", name)),
+        };
+        let weight = weight
+            .parse()
+            .map_err(|_| format!("weight of {} is not a number", name))?;
+        ops.push((op, weight));
+    }
+
+    if ops.iter().all(|(_, weight)| *weight == 0) {
+        return Err("the op mix needs at least one non-zero weight".to_string());
+    }
+    Ok(ops)
+}
+
+fn pick_op(rng: &mut XorShift, mix: &[(Op, usize)]) -> Op {
+    let total: usize = mix.iter().map(|(_, weight)| weight).sum();
+    let mut roll = rng.below(total);
+    for (op, weight) in mix {
+        if roll < *weight {
+            return *op;
+        }
+        roll -= weight;
+    }
+    mix[0].0
+}
+
+/// Checks the internal structure of a list, for the lists that can
+type Check<L> = fn(&L) -> Result<(), String>;
+
+/// Runs the workload against `list`, mirroring it on a `Vec` of the same elements,
+/// then runs `check` on the list
+fn run<L, E>(mut list: L, config: &Config, check: Check<L>) -> Result<(), String>
+where
+    L: List<E> + IntoIterator<Item = Rc<RefCell<E>>>,
+    E: Generate,
+{
+    let mut rng = XorShift(config.seed);
+    let mut model: Vec<Rc<RefCell<E>>> = Vec::with_capacity(config.size);
+
+    for _ in 0..config.size {
+        let item = Rc::new(RefCell::new(E::generate(&mut rng)));
+        list.add(item.clone());
+        model.push(item);
+    }
+
+    let start = Instant::now();
+    for step in 0..config.ops {
+        let op = pick_op(&mut rng, &config.mix);
+        let len = model.len();
+        let failed = |what: &str| format!("step {} ({:?}): {}", step, op, what);
+
+        match op {
+            Op::Add => {
+                let item = Rc::new(RefCell::new(E::generate(&mut rng)));
+                list.add(item.clone());
+                model.push(item);
+            }
+            Op::Insert => {
+                // `len` itself appends
+                let index = rng.below(len + 1);
+                let item = Rc::new(RefCell::new(E::generate(&mut rng)));
+                list.insert_at(item.clone(), index)
+                    .map_err(|err| failed(&format!("{:?}", err)))?;
+                model.insert(index, item);
+            }
+            Op::RemoveAt if len > 0 => {
+                let index = rng.below(len);
+                let removed = list
+                    .remove_at(index)
+                    .map_err(|err| failed(&format!("{:?}", err)))?;
+                if !Rc::ptr_eq(&removed, &model.remove(index)) {
+                    return Err(failed("removed the wrong element"));
+                }
+            }
+            Op::Remove if len > 0 => {
+                let item = model.remove(rng.below(len));
+                list.remove(item)
+                    .map_err(|err| failed(&format!("{:?}", err)))?;
+            }
+            Op::Get if len > 0 => {
+                let index = rng.below(len);
+                let item = list
+                    .get(index)
+                    .map_err(|err| failed(&format!("{:?}", err)))?;
+                if !Rc::ptr_eq(&item, &model[index]) {
+                    return Err(failed("got the wrong element"));
+                }
+            }
+            Op::Contains if len > 0 => {
+                let item = model[rng.below(len)].clone();
+                if !list.contains(item) {
+                    return Err(failed("lost an element"));
+                }
+            }
+            // nothing to work on in an empty list
+            _ => {}
+        }
+    }
+    let elapsed = start.elapsed();
+
+    check(&list).map_err(|err| format!("broken list: {}", err))?;
+
+    // invariants: same size, same elements in the same order
+    if list.size() != model.len() || list.is_empty() != model.is_empty() {
+        return Err(format!(
+            "size mismatch: list says {}, model has {}",
+            list.size(),
+            model.len()
+        ));
+    }
+    let mut walked = 0;
+    for (index, item) in list.into_iter().enumerate() {
+        match model.get(index) {
+            Some(expected) if Rc::ptr_eq(&item, expected) => walked += 1,
+            _ => return Err(format!("element {} differs from the model", index)),
+        }
+    }
+    if walked != model.len() {
+        return Err(format!(
+            "walked {} elements, expected {}",
+            walked,
+            model.len()
+        ));
+    }
+
+    // the list is gone, so dropping the model must free every element still in it
+    let size = model.len();
+    let elements: Vec<Weak<RefCell<E>>> = model.iter().map(Rc::downgrade).collect();
+    drop(model);
+    let leaked = elements
+        .iter()
+        .filter(|item| item.upgrade().is_some())
+        .count();
+    if leaked > 0 {
+        return Err(format!("{} elements outlived the list", leaked));
+    }
+
+    println!(
+        "{} ops in {:.3?} ({:.0} ops/s), final size {}, invariants hold",
+        config.ops,
+        elapsed,
+        config.ops as f64 / elapsed.as_secs_f64().max(f64::EPSILON),
+        size
+    );
+    Ok(())
+}
+
+fn main() {
+    let result = Config::from_args().and_then(|config| {
+        println!(
+            "running {} ops on {}<{}> (initial size {}, seed {})",
+            config.ops, config.implementation, config.elements, config.size, config.seed
+        );
+        match (config.implementation.as_str(), config.elements.as_str()) {
+            ("linked_list", "u64") => run(LinkedList::<u64>::new(), &config, |list| {
+                list.check_invariants().map_err(|err| format!("{:?}", err))
+            }),
+            ("linked_list", "string") => run(LinkedList::<String>::new(), &config, |list| {
+                list.check_invariants().map_err(|err| format!("{:?}", err))
+            }),
+            ("linked_list2", "u64") => run(LinkedList2::<u64>::new(), &config, |list| {
+                list.check_invariants().map_err(|err| format!("{:?}", err))
+            }),
+            ("linked_list2", "string") => run(LinkedList2::<String>::new(), &config, |list| {
+                list.check_invariants().map_err(|err| format!("{:?}", err))
+            }),
+            ("indexed_linked_list", "u64") => {
+                run(IndexedLinkedList::<u64>::new(), &config, |_| Ok(()))
+            }
+            ("indexed_linked_list", "string") => {
+                run(IndexedLinkedList::<String>::new(), &config, |_| Ok(()))
+            }
+            ("array_list", "u64") => run(ArrayList::<u64>::new(), &config, |_| Ok(())),
+            ("array_list", "string") => run(ArrayList::<String>::new(), &config, |_| Ok(())),
+            (implementation, elements) => Err(format!(
+                "unsupported combination --impl {} --elements {} \
+                 (expected linked_list/linked_list2/indexed_linked_list/array_list and u64/string)",
+                implementation, elements
+            )),
+        }
+    });
+
+    if let Err(err) = result {
+        eprintln!("stress: {}", err);
+        process::exit(1);
+    }
+}
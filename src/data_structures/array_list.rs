@@ -0,0 +1,276 @@
+use super::linked_list::{fmt_elements, List, ListOperationErr, SnapshotIterator};
+use std::{cell::RefCell, fmt, rc::Rc, slice};
+
+/// ### Summary
+/// A growable array holding the same `Rc<RefCell<T>>` elements as the linked lists.
+///
+/// `get` is O(1) and iteration reads one contiguous buffer, while inserting or removing
+/// anywhere but the end shifts the elements after it. It implements `List`, so it can stand
+/// in for the linked lists behind `impl List<T>` to compare them.
+pub struct ArrayList<T> {
+    items: Vec<Rc<RefCell<T>>>,
+}
+
+impl<T> ArrayList<T> {
+    /// Constructs an empty `ArrayList<T>`
+    pub const fn new() -> Self {
+        ArrayList { items: Vec::new() }
+    }
+
+    /// Constructs an empty `ArrayList<T>` with room for `capacity` elements
+    /// #### Params
+    /// - `capacity` - the number of elements to allocate room for up front
+    pub fn with_capacity(capacity: usize) -> Self {
+        ArrayList {
+            items: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// #### Returns
+    /// the number of elements the list can hold without reallocating
+    pub fn capacity(&self) -> usize {
+        self.items.capacity()
+    }
+
+    /// Check index bounds
+    pub fn index_check(&self, index: usize) -> Result<(), ListOperationErr> {
+        if self.items.len() <= index {
+            Err(ListOperationErr::IndexOutOfBounds {
+                index,
+                len: self.items.len(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// #### Returns
+    /// a reference to the first element
+    pub fn front(&self) -> Option<Rc<RefCell<T>>> {
+        self.items.first().cloned()
+    }
+
+    /// #### Returns
+    /// a reference to the last element
+    pub fn back(&self) -> Option<Rc<RefCell<T>>> {
+        self.items.last().cloned()
+    }
+
+    /// add an item to the front of the list, shifting every element in O(n)
+    /// #### Params
+    /// - `item` - a reference to the item to add
+    pub fn push_front(&mut self, item: Rc<RefCell<T>>) {
+        trace_op!(
+            list = "ArrayList",
+            op = "push_front",
+            size = self.items.len()
+        );
+        touch_nodes!(self.items.len() + 1);
+        self.items.insert(0, item);
+    }
+
+    /// add an item to the end of the list in amortized O(1), same as `add`
+    /// #### Params
+    /// - `item` - a reference to the item to add
+    pub fn push_back(&mut self, item: Rc<RefCell<T>>) {
+        self.add(item);
+    }
+
+    /// Removes the first element of the list, shifting the others in O(n)
+    pub fn shift(&mut self) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        trace_op!(list = "ArrayList", op = "shift", size = self.items.len());
+        if self.items.is_empty() {
+            return Err(ListOperationErr::OperationOnEmptyList);
+        }
+        touch_nodes!(self.items.len());
+        Ok(self.items.remove(0))
+    }
+
+    /// Removes the last element of the list
+    pub fn pop(&mut self) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        trace_op!(list = "ArrayList", op = "pop", size = self.items.len());
+        touch_nodes!(1);
+        self.items
+            .pop()
+            .ok_or(ListOperationErr::OperationOnEmptyList)
+    }
+
+    /// #### Returns
+    /// an iterator over references to the elements, without consuming the list
+    pub fn iter(&self) -> slice::Iter<'_, Rc<RefCell<T>>> {
+        self.items.iter()
+    }
+
+    /// #### Returns
+    /// an iterator over the elements the list holds right now
+    pub fn iter_snapshot(&self) -> SnapshotIterator<T> {
+        SnapshotIterator::new(self.items.clone())
+    }
+
+    /// #### Returns
+    /// the elements as a slice
+    pub fn as_slice(&self) -> &[Rc<RefCell<T>>] {
+        &self.items
+    }
+
+    /// Removes every element, keeping the buffer allocated for reuse
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+}
+
+impl<T> Default for ArrayList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for ArrayList<T> {
+    fn clone(&self) -> Self {
+        ArrayList {
+            items: self.items.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ArrayList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_elements(f, self.items.len(), self.iter(), T::fmt)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for ArrayList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_elements(f, self.items.len(), self.iter(), T::fmt)
+    }
+}
+
+/// Wraps the elements without copying them
+impl<T> From<Vec<Rc<RefCell<T>>>> for ArrayList<T> {
+    fn from(items: Vec<Rc<RefCell<T>>>) -> Self {
+        ArrayList { items }
+    }
+}
+
+impl<T> From<ArrayList<T>> for Vec<Rc<RefCell<T>>> {
+    fn from(list: ArrayList<T>) -> Self {
+        list.items
+    }
+}
+
+impl<T> IntoIterator for ArrayList<T> {
+    type Item = Rc<RefCell<T>>;
+
+    type IntoIter = std::vec::IntoIter<Rc<RefCell<T>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ArrayList<T> {
+    type Item = &'a Rc<RefCell<T>>;
+
+    type IntoIter = slice::Iter<'a, Rc<RefCell<T>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> List<T> for ArrayList<T> {
+    fn add(&mut self, item: Rc<RefCell<T>>) {
+        trace_op!(list = "ArrayList", op = "add", size = self.items.len());
+        touch_nodes!(1);
+        self.items.push(item);
+    }
+
+    fn add_raw(&mut self, item: T) {
+        self.add(Rc::new(RefCell::new(item)));
+    }
+
+    fn insert_at(&mut self, item: Rc<RefCell<T>>, index: usize) -> Result<(), ListOperationErr> {
+        trace_op!(
+            list = "ArrayList",
+            op = "insert_at",
+            index,
+            size = self.items.len()
+        );
+        if index > self.items.len() {
+            return Err(ListOperationErr::IndexOutOfBounds {
+                index,
+                len: self.items.len(),
+            });
+        }
+        touch_nodes!(self.items.len() - index + 1);
+        self.items.insert(index, item);
+        Ok(())
+    }
+
+    fn insert_raw_at(&mut self, item: T, index: usize) -> Result<(), ListOperationErr> {
+        self.insert_at(Rc::new(RefCell::new(item)), index)
+    }
+
+    fn get(&self, index: usize) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        touch_nodes!(1);
+        self.items
+            .get(index)
+            .cloned()
+            .ok_or(ListOperationErr::IndexOutOfBounds {
+                index,
+                len: self.items.len(),
+            })
+    }
+
+    fn remove(&mut self, item: Rc<RefCell<T>>) -> Result<(), ListOperationErr> {
+        trace_op!(list = "ArrayList", op = "remove", size = self.items.len());
+        if self.items.is_empty() {
+            return Err(ListOperationErr::OperationOnEmptyList);
+        }
+
+        let index = self
+            .items
+            .iter()
+            .position(|content| Rc::ptr_eq(content, &item))
+            .ok_or(ListOperationErr::ElementNotFound)?;
+        touch_nodes!(self.items.len());
+        self.items.remove(index);
+        Ok(())
+    }
+
+    fn remove_at(&mut self, index: usize) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        trace_op!(
+            list = "ArrayList",
+            op = "remove_at",
+            index,
+            size = self.items.len()
+        );
+        self.index_check(index)?;
+        touch_nodes!(self.items.len() - index);
+        Ok(self.items.remove(index))
+    }
+
+    fn contains(&self, item: Rc<RefCell<T>>) -> bool {
+        self.items.iter().any(|content| Rc::ptr_eq(content, &item))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    fn size(&self) -> usize {
+        self.items.len()
+    }
+
+    fn clear(&mut self) {
+        ArrayList::clear(self);
+    }
+
+    fn first(&self) -> Option<Rc<RefCell<T>>> {
+        self.front()
+    }
+
+    fn last(&self) -> Option<Rc<RefCell<T>>> {
+        self.back()
+    }
+}
@@ -0,0 +1,173 @@
+use super::blocking_queue::PushErr;
+use super::linked_list::ListOperationErr;
+use std::{
+    collections::VecDeque,
+    sync::{Mutex, MutexGuard},
+};
+use tokio::sync::Notify;
+
+/// ### Summary
+/// An async FIFO queue holding at most `capacity` items, the async counterpart of
+/// `BoundedBlockingQueue`.
+///
+/// `push` waits while the queue is full and `pop` waits while it is empty without
+/// blocking the executor thread; waiters are woken through `tokio::sync::Notify`.
+///
+/// Like `BoundedBlockingQueue`, it keeps its items in a `VecDeque` rather than the crate's
+/// `Queue`: the `Rc` links and elements of the lists are not `Send`, so a queue built on them
+/// could not be shared between tasks on a multi-threaded runtime.
+///
+/// If a thread panics while holding the queue lock, operations fail with
+/// `ListOperationErr::Poisoned` (or `PushErr::Poisoned`) until `recover()` is called.
+#[derive(Debug)]
+pub struct AsyncQueue<T> {
+    items: Mutex<VecDeque<T>>,
+    capacity: usize,
+    not_full: Notify,
+    not_empty: Notify,
+}
+
+impl<T> AsyncQueue<T> {
+    /// Constructs an empty `AsyncQueue<T>`
+    /// #### Params
+    /// - `capacity` - the maximum number of items the queue holds, at least 1
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "capacity must be at least 1");
+        AsyncQueue {
+            items: Mutex::new(VecDeque::with_capacity(capacity)),
+            capacity,
+            not_full: Notify::new(),
+            not_empty: Notify::new(),
+        }
+    }
+
+    /// add an item to the back of the queue, waiting for room if it is full
+    /// #### Params
+    /// - `item` - the item to add
+    pub async fn push(&self, item: T) -> Result<(), PushErr<T>> {
+        let mut item = item;
+        loop {
+            match self.try_push(item) {
+                Err(PushErr::Full(rejected)) => item = rejected,
+                result => return result,
+            }
+            self.not_full.notified().await;
+        }
+    }
+
+    /// add an item to the back of the queue if there is room
+    /// #### Params
+    /// - `item` - the item to add
+    pub fn try_push(&self, item: T) -> Result<(), PushErr<T>> {
+        let mut items = match self.items.lock() {
+            Ok(items) => items,
+            Err(_) => return Err(PushErr::Poisoned(item)),
+        };
+        if items.len() >= self.capacity {
+            return Err(PushErr::Full(item));
+        }
+        items.push_back(item);
+        if items.len() < self.capacity {
+            // a stored permit only wakes one waiter, so pass it on while there is room left
+            self.not_full.notify_one();
+        }
+        self.not_empty.notify_one();
+        Ok(())
+    }
+
+    /// Removes the item at the front of the queue, waiting for one if it is empty
+    pub async fn pop(&self) -> Result<T, ListOperationErr> {
+        loop {
+            match self.try_pop() {
+                Err(ListOperationErr::OperationOnEmptyList) => {}
+                result => return result,
+            }
+            self.not_empty.notified().await;
+        }
+    }
+
+    /// Removes the item at the front of the queue if there is one
+    pub fn try_pop(&self) -> Result<T, ListOperationErr> {
+        let mut items = self.lock()?;
+        let item = items
+            .pop_front()
+            .ok_or(ListOperationErr::OperationOnEmptyList)?;
+        if !items.is_empty() {
+            // a stored permit only wakes one waiter, so pass it on while items are left
+            self.not_empty.notify_one();
+        }
+        self.not_full.notify_one();
+        Ok(item)
+    }
+
+    /// #### Returns
+    /// `true` if a thread panicked while holding the queue lock
+    pub fn is_poisoned(&self) -> bool {
+        self.items.is_poisoned()
+    }
+
+    /// Clears the poison left by a thread that panicked while holding the queue lock,
+    /// after checking that the queue is still consistent.
+    ///
+    /// The queue is left poisoned, and `ListOperationErr::Poisoned` returned, if its
+    /// invariants no longer hold.
+    pub fn recover(&self) -> Result<(), ListOperationErr> {
+        let items = self
+            .items
+            .lock()
+            .unwrap_or_else(|poison| poison.into_inner());
+        if items.len() > self.capacity {
+            return Err(ListOperationErr::Poisoned);
+        }
+        drop(items);
+
+        self.items.clear_poison();
+        self.not_full.notify_waiters();
+        self.not_empty.notify_waiters();
+        Ok(())
+    }
+
+    /// #### Returns
+    /// Number of items in the queue
+    pub fn len(&self) -> usize {
+        self.items
+            .lock()
+            .unwrap_or_else(|poison| poison.into_inner())
+            .len()
+    }
+
+    /// #### Returns
+    /// `true` if the queue is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() < 1
+    }
+
+    /// #### Returns
+    /// `true` if a `push` would have to wait
+    pub fn is_full(&self) -> bool {
+        self.len() >= self.capacity
+    }
+
+    /// #### Returns
+    /// the maximum number of items the queue holds
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Locks the queue, failing if the lock is poisoned
+    fn lock(&self) -> Result<MutexGuard<'_, VecDeque<T>>, ListOperationErr> {
+        self.items.lock().map_err(|_| ListOperationErr::Poisoned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_send_sync<Q: Send + Sync>() {}
+
+    #[test]
+    fn can_be_shared_between_threads() {
+        assert_send_sync::<AsyncQueue<String>>();
+    }
+}
@@ -0,0 +1,139 @@
+use super::linked_list::{LinkedList, ListOperationErr};
+use std::{cell::RefCell, rc::Rc};
+
+/// ### Summary
+/// An array-backed max-heap of items of type `T`
+#[derive(Debug, Clone)]
+pub struct BinaryHeap<T: Ord> {
+    items: Vec<T>,
+}
+
+impl<T: Ord> BinaryHeap<T> {
+    /// Constructs an empty `BinaryHeap<T>`
+    pub const fn new() -> Self {
+        BinaryHeap { items: Vec::new() }
+    }
+
+    /// Constructs an empty `BinaryHeap<T>` with room for `capacity` items
+    pub fn with_capacity(capacity: usize) -> Self {
+        BinaryHeap {
+            items: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// add an item to the heap
+    /// #### Params
+    /// - `item` - the item to add
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+        self.sift_up(self.items.len() - 1);
+    }
+
+    /// Removes the greatest item of the heap
+    pub fn pop(&mut self) -> Result<T, ListOperationErr> {
+        if self.items.is_empty() {
+            return Err(ListOperationErr::OperationOnEmptyList);
+        }
+
+        let last = self.items.len() - 1;
+        self.items.swap(0, last);
+        let result = self
+            .items
+            .pop()
+            .ok_or(ListOperationErr::OperationOnEmptyList)?;
+        self.sift_down(0, self.items.len());
+
+        Ok(result)
+    }
+
+    /// #### Returns
+    /// a reference to the greatest item of the heap
+    pub fn peek(&self) -> Result<&T, ListOperationErr> {
+        self.items
+            .first()
+            .ok_or(ListOperationErr::OperationOnEmptyList)
+    }
+
+    /// #### Returns
+    /// `true` if the heap is empty
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// #### Returns
+    /// Number of items in the heap
+    pub fn size(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Consumes the heap
+    /// #### Returns
+    /// a `LinkedList<T>` holding the items of the heap in ascending order
+    pub fn into_sorted_list(mut self) -> LinkedList<T> {
+        // in-place heap sort: move the current max right behind the shrinking heap
+        let mut end = self.items.len();
+        while end > 1 {
+            end -= 1;
+            self.items.swap(0, end);
+            self.sift_down(0, end);
+        }
+
+        LinkedList::from_contents(
+            self.items
+                .into_iter()
+                .map(|item| Rc::new(RefCell::new(item)))
+                .collect(),
+        )
+    }
+
+    /// Moves the item at `index` up until its parent is not smaller
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if self.items[index] <= self.items[parent] {
+                break;
+            }
+            self.items.swap(index, parent);
+            index = parent;
+        }
+    }
+
+    /// Moves the item at `index` down until none of its children (below `end`) is greater
+    fn sift_down(&mut self, mut index: usize, end: usize) {
+        loop {
+            let left = 2 * index + 1;
+            let right = left + 1;
+            let mut largest = index;
+
+            if left < end && self.items[left] > self.items[largest] {
+                largest = left;
+            }
+            if right < end && self.items[right] > self.items[largest] {
+                largest = right;
+            }
+            if largest == index {
+                break;
+            }
+
+            self.items.swap(index, largest);
+            index = largest;
+        }
+    }
+}
+
+impl<T: Ord> Default for BinaryHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for BinaryHeap<T> {
+    fn from(items: Vec<T>) -> Self {
+        let mut heap = BinaryHeap { items };
+        let end = heap.items.len();
+        for index in (0..end / 2).rev() {
+            heap.sift_down(index, end);
+        }
+        heap
+    }
+}
@@ -0,0 +1,261 @@
+use super::linked_list::ListOperationErr;
+use std::{
+    collections::VecDeque,
+    sync::{Condvar, Mutex, MutexGuard},
+    time::Duration,
+};
+
+/// ### Summary
+/// Why an item could not be pushed onto a thread-safe queue; the item is handed back
+#[derive(Debug)]
+pub enum PushErr<T> {
+    /// the queue was at capacity
+    Full(T),
+    /// a thread panicked while holding the queue lock, see `recover()`
+    Poisoned(T),
+}
+
+impl<T> PushErr<T> {
+    /// #### Returns
+    /// the item that was not pushed
+    pub fn into_inner(self) -> T {
+        match self {
+            PushErr::Full(item) | PushErr::Poisoned(item) => item,
+        }
+    }
+}
+
+/// ### Summary
+/// A thread-safe FIFO queue holding at most `capacity` items.
+///
+/// `push` blocks while the queue is full and `pop` blocks while it is empty,
+/// so fast producers are throttled by their consumers instead of growing the queue without limit.
+///
+/// If a thread panics while holding the queue lock, operations fail with
+/// `ListOperationErr::Poisoned` (or `PushErr::Poisoned`) until `recover()` is called.
+#[derive(Debug)]
+pub struct BoundedBlockingQueue<T> {
+    items: Mutex<VecDeque<T>>,
+    capacity: usize,
+    not_full: Condvar,
+    not_empty: Condvar,
+}
+
+impl<T> BoundedBlockingQueue<T> {
+    /// Constructs an empty `BoundedBlockingQueue<T>`
+    /// #### Params
+    /// - `capacity` - the maximum number of items the queue holds, at least 1
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "capacity must be at least 1");
+        BoundedBlockingQueue {
+            items: Mutex::new(VecDeque::with_capacity(capacity)),
+            capacity,
+            not_full: Condvar::new(),
+            not_empty: Condvar::new(),
+        }
+    }
+
+    /// add an item to the back of the queue, waiting for room if it is full
+    /// #### Params
+    /// - `item` - the item to add
+    pub fn push(&self, item: T) -> Result<(), PushErr<T>> {
+        let mut items = match self.items.lock() {
+            Ok(items) => items,
+            Err(_) => return Err(PushErr::Poisoned(item)),
+        };
+        while items.len() >= self.capacity {
+            items = match self.not_full.wait(items) {
+                Ok(items) => items,
+                Err(_) => return Err(PushErr::Poisoned(item)),
+            };
+        }
+        items.push_back(item);
+        self.not_empty.notify_one();
+        Ok(())
+    }
+
+    /// add an item to the back of the queue if there is room
+    /// #### Params
+    /// - `item` - the item to add
+    pub fn try_push(&self, item: T) -> Result<(), PushErr<T>> {
+        let mut items = match self.items.lock() {
+            Ok(items) => items,
+            Err(_) => return Err(PushErr::Poisoned(item)),
+        };
+        if items.len() >= self.capacity {
+            return Err(PushErr::Full(item));
+        }
+        items.push_back(item);
+        self.not_empty.notify_one();
+        Ok(())
+    }
+
+    /// Removes the item at the front of the queue, waiting for one if it is empty
+    pub fn pop(&self) -> Result<T, ListOperationErr> {
+        let mut items = self.lock()?;
+        loop {
+            if let Some(item) = items.pop_front() {
+                self.not_full.notify_one();
+                return Ok(item);
+            }
+            items = self
+                .not_empty
+                .wait(items)
+                .map_err(|_| ListOperationErr::Poisoned)?;
+        }
+    }
+
+    /// Removes the item at the front of the queue, waiting at most `timeout` for one if it is empty
+    /// #### Params
+    /// - `timeout` - how long to wait for an item
+    pub fn pop_timeout(&self, timeout: Duration) -> Result<T, ListOperationErr> {
+        let items = self.lock()?;
+        let (mut items, _) = self
+            .not_empty
+            .wait_timeout_while(items, timeout, |items| items.is_empty())
+            .map_err(|_| ListOperationErr::Poisoned)?;
+        let item = items
+            .pop_front()
+            .ok_or(ListOperationErr::OperationOnEmptyList)?;
+        self.not_full.notify_one();
+        Ok(item)
+    }
+
+    /// Removes the item at the front of the queue if there is one
+    pub fn try_pop(&self) -> Result<T, ListOperationErr> {
+        let mut items = self.lock()?;
+        let item = items
+            .pop_front()
+            .ok_or(ListOperationErr::OperationOnEmptyList)?;
+        self.not_full.notify_one();
+        Ok(item)
+    }
+
+    /// #### Returns
+    /// `true` if a thread panicked while holding the queue lock
+    pub fn is_poisoned(&self) -> bool {
+        self.items.is_poisoned()
+    }
+
+    /// Clears the poison left by a thread that panicked while holding the queue lock,
+    /// after checking that the queue is still consistent.
+    ///
+    /// The queue is left poisoned, and `ListOperationErr::Poisoned` returned, if its
+    /// invariants no longer hold.
+    pub fn recover(&self) -> Result<(), ListOperationErr> {
+        let items = self
+            .items
+            .lock()
+            .unwrap_or_else(|poison| poison.into_inner());
+        if items.len() > self.capacity {
+            return Err(ListOperationErr::Poisoned);
+        }
+        drop(items);
+
+        self.items.clear_poison();
+        // waiters that saw the poison have given up, wake any that did not
+        self.not_full.notify_all();
+        self.not_empty.notify_all();
+        Ok(())
+    }
+
+    /// #### Returns
+    /// Number of items in the queue
+    pub fn len(&self) -> usize {
+        self.items
+            .lock()
+            .unwrap_or_else(|poison| poison.into_inner())
+            .len()
+    }
+
+    /// #### Returns
+    /// `true` if the queue is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() < 1
+    }
+
+    /// #### Returns
+    /// `true` if a `push` would have to wait
+    pub fn is_full(&self) -> bool {
+        self.len() >= self.capacity
+    }
+
+    /// #### Returns
+    /// the maximum number of items the queue holds
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Locks the queue, failing if the lock is poisoned
+    fn lock(&self) -> Result<MutexGuard<'_, VecDeque<T>>, ListOperationErr> {
+        self.items.lock().map_err(|_| ListOperationErr::Poisoned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        sync::atomic::{AtomicBool, Ordering},
+        thread,
+        time::Instant,
+    };
+
+    /// Long enough for a blocked thread to have returned if it was not blocked
+    const WAIT: Duration = Duration::from_millis(50);
+
+    #[test]
+    fn pop_blocks_until_a_producer_pushes() {
+        let queue = BoundedBlockingQueue::new(2);
+        let pushed = AtomicBool::new(false);
+        thread::scope(|scope| {
+            let consumer = scope.spawn(|| {
+                let item = queue.pop();
+                (item, pushed.load(Ordering::SeqCst))
+            });
+            thread::sleep(WAIT);
+            assert!(!consumer.is_finished());
+
+            pushed.store(true, Ordering::SeqCst);
+            queue.push(7).unwrap();
+            assert_eq!(consumer.join().unwrap(), (Ok(7), true));
+        });
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn pop_timeout_gives_up_on_an_empty_queue() {
+        let queue = BoundedBlockingQueue::<i32>::new(2);
+        let start = Instant::now();
+        assert_eq!(
+            queue.pop_timeout(WAIT),
+            Err(ListOperationErr::OperationOnEmptyList)
+        );
+        assert!(start.elapsed() >= WAIT);
+
+        thread::scope(|scope| {
+            let consumer = scope.spawn(|| queue.pop_timeout(Duration::from_secs(60)));
+            thread::sleep(WAIT);
+            queue.push(7).unwrap();
+            assert_eq!(consumer.join().unwrap(), Ok(7));
+        });
+    }
+
+    #[test]
+    fn push_blocks_while_the_queue_is_full() {
+        let queue = BoundedBlockingQueue::new(1);
+        queue.push(1).unwrap();
+        assert!(matches!(queue.try_push(2), Err(PushErr::Full(2))));
+
+        thread::scope(|scope| {
+            let producer = scope.spawn(|| queue.push(2).map_err(PushErr::into_inner));
+            thread::sleep(WAIT);
+            assert!(!producer.is_finished());
+            assert_eq!(queue.len(), 1);
+
+            assert_eq!(queue.pop(), Ok(1));
+            assert_eq!(producer.join().unwrap(), Ok(()));
+        });
+        assert_eq!(queue.try_pop(), Ok(2));
+    }
+}
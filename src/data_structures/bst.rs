@@ -0,0 +1,299 @@
+use super::linked_list::{unwrap_or_clone, LinkedList, ListOperationErr};
+use super::linked_list2::LinkedList2;
+use std::{cell::RefCell, cmp::Ordering, rc::Rc};
+
+type Subtree<T> = Option<Box<BstNode<T>>>;
+
+#[derive(Debug)]
+struct BstNode<T> {
+    content: T,
+    children: (Subtree<T>, Subtree<T>),
+}
+
+/// ### Summary
+/// An (unbalanced) binary search tree holding unique items of type `T`
+#[derive(Debug)]
+pub struct Bst<T: Ord> {
+    root: Subtree<T>,
+    size: usize,
+}
+
+impl<T: Ord> Bst<T> {
+    /// Constructs an empty `Bst<T>`
+    pub const fn new() -> Self {
+        Bst {
+            root: None,
+            size: 0,
+        }
+    }
+
+    /// Builds a tree from the elements of one of the crate's lists.
+    ///
+    /// Elements that are still shared elsewhere are cloned out of their `RefCell`.
+    /// #### Params
+    /// - `list` - the list to take the elements from
+    pub fn from_list<L>(list: L) -> Self
+    where
+        L: IntoIterator<Item = Rc<RefCell<T>>>,
+        T: Clone,
+    {
+        let mut tree = Bst::new();
+        for item in list {
+            tree.insert(unwrap_or_clone(item));
+        }
+        tree
+    }
+
+    /// add an item to the tree
+    /// #### Params
+    /// - `item` - the item to add
+    /// #### Returns
+    /// `false` if an equal item was already in the tree
+    pub fn insert(&mut self, item: T) -> bool {
+        let mut cur = &mut self.root;
+        while let Some(node) = cur {
+            cur = match item.cmp(&node.content) {
+                Ordering::Less => &mut node.children.0,
+                Ordering::Greater => &mut node.children.1,
+                Ordering::Equal => return false,
+            };
+        }
+
+        *cur = Some(Box::new(BstNode {
+            content: item,
+            children: (None, None),
+        }));
+        self.size += 1;
+        true
+    }
+
+    /// checks whether `item` is in the tree
+    /// #### Params
+    /// - `item` - the item to lookup
+    pub fn contains(&self, item: &T) -> bool {
+        let mut cur = &self.root;
+        while let Some(node) = cur {
+            cur = match item.cmp(&node.content) {
+                Ordering::Less => &node.children.0,
+                Ordering::Greater => &node.children.1,
+                Ordering::Equal => return true,
+            };
+        }
+        false
+    }
+
+    /// removes the item equal to `item` from the tree
+    /// #### Params
+    /// - `item` - the item to be removed
+    pub fn remove(&mut self, item: &T) -> Result<T, ListOperationErr> {
+        let mut cur = &mut self.root;
+        // stops at the node equal to `item`, or the empty slot it would go in
+        let cur = loop {
+            cur = match (cur.as_ref().map(|node| item.cmp(&node.content)), cur) {
+                (Some(Ordering::Less), Some(node)) => &mut node.children.0,
+                (Some(Ordering::Greater), Some(node)) => &mut node.children.1,
+                (_, slot) => break slot,
+            };
+        };
+
+        let mut node = cur.take().ok_or(ListOperationErr::ElementNotFound)?;
+        *cur = match (node.children.0.take(), node.children.1.take()) {
+            (None, None) => None,
+            (Some(n), None) | (None, Some(n)) => Some(n),
+            (Some(n0), Some(n1)) => {
+                // replace with the smallest node of the right subtree
+                let mut right = Some(n1);
+                let mut successor = Self::take_min(&mut right);
+                successor.children = (Some(n0), right);
+                Some(successor)
+            }
+        };
+        self.size -= 1;
+
+        Ok(node.content)
+    }
+
+    /// #### Returns
+    /// an iterator over the items of the tree in ascending order
+    pub fn iter(&self) -> BstIterator<'_, T> {
+        let mut iter = BstIterator { stack: Vec::new() };
+        iter.push_left(&self.root);
+        iter
+    }
+
+    /// #### Returns
+    /// `true` if the tree is empty
+    pub fn is_empty(&self) -> bool {
+        self.size < 1
+    }
+
+    /// #### Returns
+    /// Number of items in the tree
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Consumes the tree
+    /// #### Returns
+    /// a `LinkedList<T>` holding the items of the tree in ascending order
+    pub fn into_list(self) -> LinkedList<T> {
+        LinkedList::from_contents(self.into_contents())
+    }
+
+    /// Consumes the tree
+    /// #### Returns
+    /// a `LinkedList2<T>` holding the items of the tree in ascending order
+    pub fn into_list2(self) -> LinkedList2<T>
+    where
+        T: std::fmt::Debug,
+    {
+        LinkedList2::from_contents(self.into_contents())
+    }
+
+    /// Drains the tree in order into list contents
+    fn into_contents(mut self) -> Vec<Rc<RefCell<T>>> {
+        let mut contents = Vec::with_capacity(self.size);
+        let mut stack = Vec::new();
+        let mut cur = self.root.take();
+
+        loop {
+            while let Some(mut node) = cur {
+                cur = node.children.0.take();
+                stack.push(node);
+            }
+            match stack.pop() {
+                Some(mut node) => {
+                    cur = node.children.1.take();
+                    contents.push(Rc::new(RefCell::new(node.content)));
+                }
+                None => break,
+            }
+        }
+
+        contents
+    }
+
+    /// Detaches the smallest node of the non-empty subtree `tree`
+    fn take_min(tree: &mut Subtree<T>) -> Box<BstNode<T>> {
+        let mut cur = tree;
+        while cur.as_ref().is_some_and(|node| node.children.0.is_some()) {
+            cur = &mut cur.as_mut().unwrap().children.0;
+        }
+
+        let mut min = cur.take().expect("subtree should not be empty");
+        *cur = min.children.1.take();
+        min
+    }
+}
+
+impl<T: Ord + Clone> Clone for Bst<T> {
+    fn clone(&self) -> Self {
+        // cloning nodes recursively could overflow the stack on degenerate trees, so copy them
+        // in post-order instead: each node once both of its subtrees are copied
+        let mut pending = vec![(self.root.as_deref(), false)];
+        let mut copied: Vec<Subtree<T>> = Vec::new();
+        while let Some((node, children_copied)) = pending.pop() {
+            match node {
+                None => copied.push(None),
+                Some(node) if children_copied => {
+                    let right = copied.pop().flatten();
+                    let left = copied.pop().flatten();
+                    copied.push(Some(Box::new(BstNode {
+                        content: node.content.clone(),
+                        children: (left, right),
+                    })));
+                }
+                Some(node) => {
+                    pending.push((Some(node), true));
+                    pending.push((node.children.1.as_deref(), false));
+                    pending.push((node.children.0.as_deref(), false));
+                }
+            }
+        }
+
+        Bst {
+            root: copied.pop().flatten(),
+            size: self.size,
+        }
+    }
+}
+
+impl<T: Ord> Drop for Bst<T> {
+    fn drop(&mut self) {
+        // dropping the root would drop every node below it recursively and could overflow
+        // the stack on degenerate trees
+        let mut stack: Vec<Box<BstNode<T>>> = self.root.take().into_iter().collect();
+        while let Some(mut node) = stack.pop() {
+            stack.extend(node.children.0.take());
+            stack.extend(node.children.1.take());
+        }
+    }
+}
+
+impl<T: Ord> Default for Bst<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// In-order iterator over the items of a `Bst<T>`
+pub struct BstIterator<'a, T> {
+    stack: Vec<&'a BstNode<T>>,
+}
+
+impl<'a, T> BstIterator<'a, T> {
+    /// Pushes `node` and its chain of left children onto the stack
+    fn push_left(&mut self, mut node: &'a Subtree<T>) {
+        while let Some(n) = node {
+            self.stack.push(n);
+            node = &n.children.0;
+        }
+    }
+}
+
+impl<'a, T> Iterator for BstIterator<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left(&node.children.1);
+        Some(&node.content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn degenerate_trees_clone_and_drop_without_recursion() {
+        // a small stack, so recursing once per node would overflow it
+        let handle = thread::Builder::new().stack_size(256 * 1024);
+        let handle = handle.spawn(|| {
+            let mut tree = Bst::new();
+            for i in 0..20_000 {
+                tree.insert(i);
+            }
+            let copy = tree.clone();
+            drop(tree);
+            assert_eq!(copy.size(), 20_000);
+            assert!(copy.iter().copied().eq(0..20_000));
+        });
+        handle.unwrap().join().unwrap();
+    }
+
+    #[test]
+    fn clones_are_independent() {
+        let mut tree = Bst::new();
+        for i in [5, 2, 8, 1, 3, 9] {
+            tree.insert(i);
+        }
+        let mut copy = tree.clone();
+        copy.remove(&2).unwrap();
+        copy.insert(4);
+        assert!(tree.iter().copied().eq([1, 2, 3, 5, 8, 9]));
+        assert!(copy.iter().copied().eq([1, 3, 4, 5, 8, 9]));
+    }
+}
@@ -0,0 +1,531 @@
+use super::linked_list::{fmt_elements, List, ListOperationErr, SnapshotIterator};
+use std::{cell::RefCell, fmt, rc::Rc};
+
+struct CircularNode<T> {
+    content: Rc<RefCell<T>>,
+    /// the next node; the tail links back to the head
+    next: Option<Rc<RefCell<CircularNode<T>>>>,
+}
+
+impl<T> CircularNode<T> {
+    /// Creates a new node with no linked node
+    /// ### Returns
+    /// a reference to the newly created node
+    fn new(content: Rc<RefCell<T>>) -> Rc<RefCell<CircularNode<T>>> {
+        Rc::new(RefCell::new(CircularNode {
+            content,
+            next: None,
+        }))
+    }
+
+    /// Reads a node without tracking the borrow
+    fn peek(node: &RefCell<CircularNode<T>>) -> &CircularNode<T> {
+        // SAFETY: the ring never hands its nodes out, and the only `RefMut`s to them are taken
+        // by `add`, `insert_at`, `unlink_at` and `clear`, which all take the list by
+        // `&mut self`. Readers, including `CircularIter` and `CircularIterCycle`, borrow the
+        // list shared for as long as they hold node references, so none of those can run.
+        unsafe { &*node.as_ptr() }
+    }
+}
+
+/// ### Summary
+/// A singly linked ring: the tail links back to the head.
+///
+/// Only the tail is stored, its successor being the head, so adding at either end and
+/// `rotate()` are O(1). Iterating with `iter_cycle()` wraps around forever, e.g. for
+/// round-robin scheduling.
+pub struct CircularLinkedList<T> {
+    tail: Option<Rc<RefCell<CircularNode<T>>>>,
+    size: usize,
+}
+
+impl<T> CircularLinkedList<T> {
+    /// Constructs an empty `CircularLinkedList<T>`
+    pub const fn new() -> Self {
+        CircularLinkedList {
+            tail: None,
+            size: 0,
+        }
+    }
+
+    /// Check index bounds
+    pub fn index_check(&self, index: usize) -> Result<(), ListOperationErr> {
+        if self.size <= index {
+            Err(ListOperationErr::IndexOutOfBounds {
+                index,
+                len: self.size,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// #### Returns
+    /// a reference to the first element
+    pub fn front(&self) -> Option<Rc<RefCell<T>>> {
+        let tail = CircularNode::peek(self.tail.as_ref()?);
+        let head = CircularNode::peek(tail.next.as_ref()?);
+        Some(head.content.clone())
+    }
+
+    /// #### Returns
+    /// a reference to the last element
+    pub fn back(&self) -> Option<Rc<RefCell<T>>> {
+        Some(CircularNode::peek(self.tail.as_ref()?).content.clone())
+    }
+
+    /// Moves the first element to the back, making the second element the head
+    pub fn rotate(&mut self) {
+        if let Some(tail) = self.tail.take() {
+            self.tail = tail.borrow().next.clone();
+        }
+    }
+
+    /// Rotates the list `n` times, see `rotate`
+    /// #### Params
+    /// - `n` - how many elements to move from the front to the back
+    pub fn rotate_by(&mut self, n: usize) {
+        if self.size > 0 {
+            for _ in 0..n % self.size {
+                self.rotate();
+            }
+        }
+    }
+
+    /// Removes the first element of the list
+    pub fn shift(&mut self) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        trace_op!(list = "CircularLinkedList", op = "shift", size = self.size);
+        if self.is_empty() {
+            return Err(ListOperationErr::OperationOnEmptyList);
+        }
+
+        self.unlink_at(0)
+    }
+
+    /// #### Returns
+    /// an iterator over references to the elements, one lap from the head
+    pub fn iter(&self) -> CircularIter<'_, T> {
+        CircularIter {
+            current: self.head_node(),
+            remaining: self.size,
+        }
+    }
+
+    /// #### Returns
+    /// an endless iterator over references to the elements, wrapping from the tail to the head;
+    /// empty if the list is empty
+    pub fn iter_cycle(&self) -> CircularIterCycle<'_, T> {
+        CircularIterCycle {
+            current: self.head_node(),
+        }
+    }
+
+    /// #### Returns
+    /// an iterator over the elements the list holds right now, one lap from the head
+    pub fn iter_snapshot(&self) -> SnapshotIterator<T> {
+        SnapshotIterator::new(self.iter().cloned().collect())
+    }
+
+    /// Removes every element, unlinking the nodes one by one
+    pub fn clear(&mut self) {
+        if let Some(tail) = self.tail.take() {
+            // break the ring, then drop the chain iteratively
+            let mut cur = tail.borrow_mut().next.take();
+            while let Some(node) = cur {
+                cur = node.borrow_mut().next.take();
+            }
+        }
+        self.size = 0;
+    }
+
+    fn head_node(&self) -> Option<&RefCell<CircularNode<T>>> {
+        CircularNode::peek(self.tail.as_deref()?).next.as_deref()
+    }
+
+    /// Walks `index` nodes past the tail
+    /// #### Returns
+    /// the node before the one at `index`, the tail for index 0
+    fn node_before(&self, index: usize) -> Result<Rc<RefCell<CircularNode<T>>>, ListOperationErr> {
+        self.index_check(index)?;
+
+        touch_nodes!(index + 1);
+        let Some(mut cur) = self.tail.clone() else {
+            unreachable!("index_check rejects every index of an empty list");
+        };
+        for _ in 0..index {
+            let Some(next) = cur.borrow().next.clone() else {
+                unreachable!("every node of the ring links to a next one");
+            };
+            cur = next;
+        }
+        Ok(cur)
+    }
+
+    /// Unlinks the node at `index`, keeping `tail` and `size` in sync
+    /// #### Returns
+    /// a reference to the item held by the unlinked node
+    fn unlink_at(&mut self, index: usize) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        let prev = self.node_before(index)?;
+        let Some(removed) = prev.borrow_mut().next.take() else {
+            unreachable!("every node of the ring links to a next one");
+        };
+
+        if Rc::ptr_eq(&removed, &prev) {
+            // the only node linked to itself
+            self.tail = None;
+        } else {
+            prev.borrow_mut().next = removed.borrow_mut().next.take();
+            if self
+                .tail
+                .as_ref()
+                .is_some_and(|tail| Rc::ptr_eq(tail, &removed))
+            {
+                self.tail = Some(prev);
+            }
+        }
+        self.size -= 1;
+
+        let content = removed.borrow().content.clone();
+        Ok(content)
+    }
+}
+
+impl<T> Default for CircularLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for CircularLinkedList<T> {
+    fn drop(&mut self) {
+        // the ring keeps itself alive until it is broken
+        self.clear();
+    }
+}
+
+impl<T> Clone for CircularLinkedList<T> {
+    fn clone(&self) -> Self {
+        let mut clone = CircularLinkedList::new();
+        for content in self.iter() {
+            clone.add(content.clone());
+        }
+        clone
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for CircularLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_elements(f, self.size, self.iter(), T::fmt)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for CircularLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_elements(f, self.size, self.iter(), T::fmt)
+    }
+}
+
+/// Iterator over one lap of a `CircularLinkedList`, see `CircularLinkedList::iter`
+pub struct CircularIter<'a, T> {
+    current: Option<&'a RefCell<CircularNode<T>>>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for CircularIter<'a, T> {
+    type Item = &'a Rc<RefCell<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = CircularNode::peek(self.current?);
+        self.current = node.next.as_deref();
+        self.remaining -= 1;
+        Some(&node.content)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for CircularIter<'a, T> {}
+
+/// Endless iterator around a `CircularLinkedList`, see `CircularLinkedList::iter_cycle`
+pub struct CircularIterCycle<'a, T> {
+    current: Option<&'a RefCell<CircularNode<T>>>,
+}
+
+impl<'a, T> Iterator for CircularIterCycle<'a, T> {
+    type Item = &'a Rc<RefCell<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = CircularNode::peek(self.current?);
+        self.current = node.next.as_deref();
+        Some(&node.content)
+    }
+}
+
+impl<T> IntoIterator for CircularLinkedList<T> {
+    type Item = Rc<RefCell<T>>;
+
+    type IntoIter = SnapshotIterator<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_snapshot()
+    }
+}
+
+impl<T> List<T> for CircularLinkedList<T> {
+    fn add(&mut self, item: Rc<RefCell<T>>) {
+        trace_op!(list = "CircularLinkedList", op = "add", size = self.size);
+        touch_nodes!(2);
+        let node = CircularNode::new(item);
+        match self.tail.replace(node.clone()) {
+            Some(tail) => {
+                let head = tail.borrow_mut().next.replace(node.clone());
+                node.borrow_mut().next = head;
+            }
+            // a single node links to itself
+            None => node.borrow_mut().next = Some(node.clone()),
+        }
+        self.size += 1;
+    }
+
+    fn add_raw(&mut self, item: T) {
+        self.add(Rc::new(RefCell::new(item)));
+    }
+
+    fn insert_at(&mut self, item: Rc<RefCell<T>>, index: usize) -> Result<(), ListOperationErr> {
+        trace_op!(
+            list = "CircularLinkedList",
+            op = "insert_at",
+            index,
+            size = self.size
+        );
+        if index == self.size {
+            // the new node becomes the tail
+            self.add(item);
+            return Ok(());
+        }
+        let prev = self.node_before(index)?;
+        let node = CircularNode::new(item);
+        let next = prev.borrow_mut().next.replace(node.clone());
+        node.borrow_mut().next = next;
+        self.size += 1;
+        Ok(())
+    }
+
+    fn insert_raw_at(&mut self, item: T, index: usize) -> Result<(), ListOperationErr> {
+        self.insert_at(Rc::new(RefCell::new(item)), index)
+    }
+
+    fn get(&self, index: usize) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        self.index_check(index)?;
+        self.iter()
+            .nth(index)
+            .cloned()
+            .ok_or(ListOperationErr::IndexOutOfBounds {
+                index,
+                len: self.size,
+            })
+    }
+
+    fn remove(&mut self, item: Rc<RefCell<T>>) -> Result<(), ListOperationErr> {
+        trace_op!(list = "CircularLinkedList", op = "remove", size = self.size);
+        if self.is_empty() {
+            return Err(ListOperationErr::OperationOnEmptyList);
+        }
+
+        let index = self
+            .iter()
+            .position(|content| Rc::ptr_eq(content, &item))
+            .ok_or(ListOperationErr::ElementNotFound)?;
+        self.unlink_at(index)?;
+        Ok(())
+    }
+
+    fn remove_at(&mut self, index: usize) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        trace_op!(
+            list = "CircularLinkedList",
+            op = "remove_at",
+            index,
+            size = self.size
+        );
+        self.unlink_at(index)
+    }
+
+    fn contains(&self, item: Rc<RefCell<T>>) -> bool {
+        self.iter().any(|content| Rc::ptr_eq(content, &item))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.size < 1
+    }
+
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn clear(&mut self) {
+        CircularLinkedList::clear(self);
+    }
+
+    fn first(&self) -> Option<Rc<RefCell<T>>> {
+        self.front()
+    }
+
+    fn last(&self) -> Option<Rc<RefCell<T>>> {
+        self.back()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Weak;
+
+    fn ring_of(items: std::ops::Range<i32>) -> CircularLinkedList<i32> {
+        let mut list = CircularLinkedList::new();
+        for i in items {
+            list.add_raw(i);
+        }
+        list
+    }
+
+    fn values(list: &CircularLinkedList<i32>) -> Vec<i32> {
+        list.iter().map(|item| *item.borrow()).collect()
+    }
+
+    fn ends(list: &CircularLinkedList<i32>) -> (Option<i32>, Option<i32>) {
+        (
+            list.front().map(|item| *item.borrow()),
+            list.back().map(|item| *item.borrow()),
+        )
+    }
+
+    #[test]
+    fn rotate_moves_the_head_to_the_back() {
+        let mut list = ring_of(0..4);
+        list.rotate();
+        assert_eq!(values(&list), [1, 2, 3, 0]);
+        list.rotate_by(6);
+        assert_eq!(values(&list), [3, 0, 1, 2]);
+        assert_eq!(ends(&list), (Some(3), Some(2)));
+
+        let mut empty = ring_of(0..0);
+        empty.rotate();
+        empty.rotate_by(3);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn insert_at_front_goes_after_the_tail() {
+        let mut list = ring_of(0..3);
+        list.insert_raw_at(-1, 0).unwrap();
+        assert_eq!(values(&list), [-1, 0, 1, 2]);
+        assert_eq!(ends(&list), (Some(-1), Some(2)));
+        list.insert_raw_at(9, 4).unwrap();
+        assert_eq!(ends(&list), (Some(-1), Some(9)));
+        assert_eq!(
+            list.insert_raw_at(7, 6).unwrap_err(),
+            ListOperationErr::IndexOutOfBounds { index: 6, len: 5 }
+        );
+
+        let mut empty = ring_of(0..0);
+        empty.insert_raw_at(5, 0).unwrap();
+        assert_eq!(ends(&empty), (Some(5), Some(5)));
+    }
+
+    #[test]
+    fn removing_the_only_node_empties_the_ring() {
+        let mut list = ring_of(0..1);
+        assert_eq!(*list.remove_at(0).unwrap().borrow(), 0);
+        assert!(list.is_empty());
+        assert_eq!(ends(&list), (None, None));
+        assert_eq!(list.iter_cycle().next(), None);
+        assert_eq!(
+            list.shift().unwrap_err(),
+            ListOperationErr::OperationOnEmptyList
+        );
+
+        list.add_raw(1);
+        assert_eq!(values(&list), [1]);
+    }
+
+    #[test]
+    fn removing_the_last_index_moves_the_tail_back() {
+        let mut list = ring_of(0..3);
+        assert_eq!(*list.remove_at(2).unwrap().borrow(), 2);
+        assert_eq!(ends(&list), (Some(0), Some(1)));
+        list.add_raw(3);
+        assert_eq!(values(&list), [0, 1, 3]);
+        assert_eq!(*list.shift().unwrap().borrow(), 0);
+        assert_eq!(ends(&list), (Some(1), Some(3)));
+    }
+
+    #[test]
+    fn get_and_remove_check_every_index() {
+        for len in [0, 1, 5] {
+            let list = ring_of(0..len as i32);
+            for index in 0..len + 2 {
+                match list.get(index) {
+                    Ok(item) if index < len => assert_eq!(*item.borrow(), index as i32),
+                    result => assert_eq!(
+                        result.unwrap_err(),
+                        ListOperationErr::IndexOutOfBounds { index, len }
+                    ),
+                }
+
+                let mut list = ring_of(0..len as i32);
+                let mut model: Vec<i32> = (0..len as i32).collect();
+                match list.remove_at(index) {
+                    Ok(item) if index < len => assert_eq!(*item.borrow(), model.remove(index)),
+                    result => assert_eq!(
+                        result.unwrap_err(),
+                        ListOperationErr::IndexOutOfBounds { index, len }
+                    ),
+                }
+                assert_eq!(values(&list), model);
+                assert_eq!(ends(&list), (model.first().copied(), model.last().copied()));
+            }
+        }
+    }
+
+    #[test]
+    fn iter_cycle_wraps_around() {
+        let list = ring_of(0..3);
+        let lap: Vec<i32> = list
+            .iter_cycle()
+            .take(8)
+            .map(|item| *item.borrow())
+            .collect();
+        assert_eq!(lap, [0, 1, 2, 0, 1, 2, 0, 1]);
+        assert_eq!(list.iter().len(), 3);
+    }
+
+    fn tracked(list: &mut CircularLinkedList<i32>, len: i32) -> Vec<Weak<RefCell<i32>>> {
+        (0..len)
+            .map(|i| {
+                let item = Rc::new(RefCell::new(i));
+                let weak = Rc::downgrade(&item);
+                list.add(item);
+                weak
+            })
+            .collect()
+    }
+
+    #[test]
+    fn clear_and_drop_break_the_ring() {
+        let mut list = CircularLinkedList::new();
+        let cleared = tracked(&mut list, 4);
+        list.clear();
+        assert!(list.is_empty());
+        assert!(cleared.iter().all(|item| item.upgrade().is_none()));
+
+        let dropped = tracked(&mut list, 4);
+        list.rotate();
+        drop(list);
+        assert!(dropped.iter().all(|item| item.upgrade().is_none()));
+    }
+}
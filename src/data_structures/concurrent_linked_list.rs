@@ -0,0 +1,248 @@
+use super::linked_list::ListOperationErr;
+use std::{
+    collections::LinkedList as StdLinkedList,
+    sync::{Arc, Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+/// ### Summary
+/// A thread-safe linked list, shareable across threads (e.g. behind an `Arc`).
+///
+/// It mirrors the `List` surface with `Arc<Mutex<T>>` elements in place of `Rc<RefCell<T>>`,
+/// which is why it cannot implement `List` itself. All methods take `&self`; the nodes sit
+/// behind a single `RwLock`, so lookups run in parallel and structural changes are exclusive.
+///
+/// If a thread panics while holding the write lock, operations fail with
+/// `ListOperationErr::Poisoned` until `recover()` is called.
+#[derive(Debug, Default)]
+pub struct ConcurrentLinkedList<T> {
+    nodes: RwLock<StdLinkedList<Arc<Mutex<T>>>>,
+}
+
+impl<T> ConcurrentLinkedList<T> {
+    /// Constructs an empty `ConcurrentLinkedList<T>`
+    pub const fn new() -> Self {
+        ConcurrentLinkedList {
+            nodes: RwLock::new(StdLinkedList::new()),
+        }
+    }
+
+    /// add an item to the end of the list
+    /// #### Params
+    /// - `item` - a reference to the item to add
+    pub fn add(&self, item: Arc<Mutex<T>>) -> Result<(), ListOperationErr> {
+        self.write()?.push_back(item);
+        Ok(())
+    }
+
+    /// add an item to the end of the list
+    /// #### Params
+    /// - `item` - the item to add
+    pub fn add_raw(&self, item: T) -> Result<(), ListOperationErr> {
+        self.add(Arc::new(Mutex::new(item)))
+    }
+
+    /// add an item to the front of the list
+    /// #### Params
+    /// - `item` - a reference to the item to add
+    pub fn push_front(&self, item: Arc<Mutex<T>>) -> Result<(), ListOperationErr> {
+        self.write()?.push_front(item);
+        Ok(())
+    }
+
+    /// insert an item at the specified index
+    /// #### Params
+    /// - `item` - a reference to the item to insert
+    /// - `index` - the index to insert the item at, the length appends it
+    pub fn insert_at(&self, item: Arc<Mutex<T>>, index: usize) -> Result<(), ListOperationErr> {
+        let mut nodes = self.write()?;
+        if index > nodes.len() {
+            return Err(ListOperationErr::IndexOutOfBounds {
+                index,
+                len: nodes.len(),
+            });
+        }
+        let mut rest = nodes.split_off(index);
+        nodes.push_back(item);
+        nodes.append(&mut rest);
+        Ok(())
+    }
+
+    /// insert an item at the specified index
+    /// #### Params
+    /// - `item` - the item to insert
+    /// - `index` - the index to insert the item at
+    pub fn insert_raw_at(&self, item: T, index: usize) -> Result<(), ListOperationErr> {
+        self.insert_at(Arc::new(Mutex::new(item)), index)
+    }
+
+    /// get a reference to the item at the specified index
+    /// #### Params
+    /// - `index` - the index to lookup
+    pub fn get(&self, index: usize) -> Result<Arc<Mutex<T>>, ListOperationErr> {
+        let nodes = self.read()?;
+        nodes
+            .iter()
+            .nth(index)
+            .cloned()
+            .ok_or(ListOperationErr::IndexOutOfBounds {
+                index,
+                len: nodes.len(),
+            })
+    }
+
+    /// Removes the first element of the list
+    pub fn shift(&self) -> Result<Arc<Mutex<T>>, ListOperationErr> {
+        self.write()?
+            .pop_front()
+            .ok_or(ListOperationErr::OperationOnEmptyList)
+    }
+
+    /// Removes the last element of the list
+    pub fn pop(&self) -> Result<Arc<Mutex<T>>, ListOperationErr> {
+        self.write()?
+            .pop_back()
+            .ok_or(ListOperationErr::OperationOnEmptyList)
+    }
+
+    /// removes the specified `item` from the list, matched by `Arc` pointer identity
+    /// #### Params
+    /// - `item` - a reference to the item to be removed
+    pub fn remove(&self, item: &Arc<Mutex<T>>) -> Result<(), ListOperationErr> {
+        let mut nodes = self.write()?;
+        if nodes.is_empty() {
+            return Err(ListOperationErr::OperationOnEmptyList);
+        }
+        let index = nodes
+            .iter()
+            .position(|content| Arc::ptr_eq(content, item))
+            .ok_or(ListOperationErr::ElementNotFound)?;
+        match Self::unlink_at(&mut nodes, index) {
+            Some(_) => Ok(()),
+            None => unreachable!("`position` only finds indices in bounds"),
+        }
+    }
+
+    /// removes the item at the specified `index`
+    /// #### Params
+    /// - `index` - the index of the item to remove
+    pub fn remove_at(&self, index: usize) -> Result<Arc<Mutex<T>>, ListOperationErr> {
+        let mut nodes = self.write()?;
+        if nodes.is_empty() {
+            return Err(ListOperationErr::OperationOnEmptyList);
+        }
+        let len = nodes.len();
+        Self::unlink_at(&mut nodes, index).ok_or(ListOperationErr::IndexOutOfBounds { index, len })
+    }
+
+    /// checks whether `item` is in the list, matched by `Arc` pointer identity
+    /// #### Params
+    /// - `item` - the item to lookup
+    pub fn contains(&self, item: &Arc<Mutex<T>>) -> bool {
+        self.nodes
+            .read()
+            .unwrap_or_else(|poison| poison.into_inner())
+            .iter()
+            .any(|content| Arc::ptr_eq(content, item))
+    }
+
+    /// #### Returns
+    /// a copy of the element references the list holds right now
+    pub fn snapshot(&self) -> Result<Vec<Arc<Mutex<T>>>, ListOperationErr> {
+        Ok(self.read()?.iter().cloned().collect())
+    }
+
+    /// #### Returns
+    /// `true` if the list is empty
+    pub fn is_empty(&self) -> bool {
+        self.size() < 1
+    }
+
+    /// #### Returns
+    /// Number of elements in list
+    pub fn size(&self) -> usize {
+        self.nodes
+            .read()
+            .unwrap_or_else(|poison| poison.into_inner())
+            .len()
+    }
+
+    /// #### Returns
+    /// `true` if a thread panicked while changing the list
+    pub fn is_poisoned(&self) -> bool {
+        self.nodes.is_poisoned()
+    }
+
+    /// Clears the poison left by a thread that panicked while changing the list,
+    /// after checking that the list is still consistent.
+    ///
+    /// The list is left poisoned, and `ListOperationErr::Poisoned` returned, if its
+    /// invariants no longer hold.
+    pub fn recover(&self) -> Result<(), ListOperationErr> {
+        let nodes = self
+            .nodes
+            .read()
+            .unwrap_or_else(|poison| poison.into_inner());
+        // walking from either end must reach as many nodes as the list counts
+        let len = nodes.len();
+        if nodes.iter().count() != len || nodes.iter().rev().count() != len {
+            return Err(ListOperationErr::Poisoned);
+        }
+        drop(nodes);
+
+        self.nodes.clear_poison();
+        Ok(())
+    }
+
+    /// Unlinks the element at `index`
+    /// #### Returns
+    /// the removed element, `None` if `index` is out of bounds
+    fn unlink_at(nodes: &mut StdLinkedList<Arc<Mutex<T>>>, index: usize) -> Option<Arc<Mutex<T>>> {
+        if index >= nodes.len() {
+            return None;
+        }
+        let mut rest = nodes.split_off(index);
+        let removed = rest.pop_front();
+        nodes.append(&mut rest);
+        removed
+    }
+
+    /// Locks the list for reading, failing if the lock is poisoned
+    fn read(&self) -> Result<RwLockReadGuard<'_, StdLinkedList<Arc<Mutex<T>>>>, ListOperationErr> {
+        self.nodes.read().map_err(|_| ListOperationErr::Poisoned)
+    }
+
+    /// Locks the list for writing, failing if the lock is poisoned
+    fn write(
+        &self,
+    ) -> Result<RwLockWriteGuard<'_, StdLinkedList<Arc<Mutex<T>>>>, ListOperationErr> {
+        self.nodes.write().map_err(|_| ListOperationErr::Poisoned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn recover_clears_the_poison_of_a_consistent_list() {
+        let list = Arc::new(ConcurrentLinkedList::new());
+        list.add_raw(1).unwrap();
+
+        let poisoner = list.clone();
+        let panicked = thread::spawn(move || {
+            let mut nodes = poisoner.write().unwrap();
+            nodes.push_back(Arc::new(Mutex::new(2)));
+            panic!("poison the list");
+        })
+        .join();
+        assert!(panicked.is_err());
+        assert!(list.is_poisoned());
+        assert_eq!(list.add_raw(3).unwrap_err(), ListOperationErr::Poisoned);
+
+        list.recover().unwrap();
+        assert!(!list.is_poisoned());
+        list.add_raw(3).unwrap();
+        assert_eq!(list.size(), 3);
+    }
+}
@@ -0,0 +1,30 @@
+use super::linked_list::ListOperationErr;
+use std::{cell::RefCell, rc::Rc};
+
+/// ### Summary
+/// Represents a double-ended queue of items of type `T`, with O(1) access to both ends
+pub trait Deque<T> {
+    /// add an item to the front
+    /// #### Params
+    /// - `item` - a reference to the item to add
+    fn push_front(&mut self, item: Rc<RefCell<T>>);
+
+    /// add an item to the back
+    /// #### Params
+    /// - `item` - a reference to the item to add
+    fn push_back(&mut self, item: Rc<RefCell<T>>);
+
+    /// Removes the item at the front
+    fn pop_front(&mut self) -> Result<Rc<RefCell<T>>, ListOperationErr>;
+
+    /// Removes the item at the back
+    fn pop_back(&mut self) -> Result<Rc<RefCell<T>>, ListOperationErr>;
+
+    /// #### Returns
+    /// a reference to the item at the front
+    fn front(&self) -> Option<Rc<RefCell<T>>>;
+
+    /// #### Returns
+    /// a reference to the item at the back
+    fn back(&self) -> Option<Rc<RefCell<T>>>;
+}
@@ -0,0 +1,111 @@
+use super::linked_list::{List, SnapshotIterator};
+use super::linked_list2::LinkedList2;
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+/// An element of an `ExpiringList` together with the moment it stops being live
+#[derive(Debug)]
+struct Entry<T> {
+    expires_at: Instant,
+    item: Rc<RefCell<T>>,
+}
+
+/// ### Summary
+/// A `LinkedList2` whose elements expire a fixed time after they were added.
+///
+/// Expired elements are never yielded. They are unlinked by `purge_expired()`,
+/// which `iter_snapshot()` and `size()` run first, so the list cleans itself up as it is read.
+pub struct ExpiringList<T: std::fmt::Debug> {
+    list: LinkedList2<Entry<T>>,
+    ttl: Duration,
+}
+
+impl<T: std::fmt::Debug> ExpiringList<T> {
+    /// Constructs an empty `ExpiringList<T>`
+    /// #### Params
+    /// - `ttl` - how long an added element stays live, unless added with its own ttl
+    pub const fn new(ttl: Duration) -> Self {
+        ExpiringList {
+            list: LinkedList2::new(),
+            ttl,
+        }
+    }
+
+    /// add an item to the end of the list, live for the list's ttl
+    /// #### Params
+    /// - `item` - a reference to the item to add
+    pub fn add(&mut self, item: Rc<RefCell<T>>) {
+        self.add_with_ttl(item, self.ttl);
+    }
+
+    /// add an item to the end of the list, live for the list's ttl
+    /// #### Params
+    /// - `item` - the item to add
+    pub fn add_raw(&mut self, item: T) {
+        self.add(Rc::new(RefCell::new(item)));
+    }
+
+    /// add an item to the end of the list
+    /// #### Params
+    /// - `item` - a reference to the item to add
+    /// - `ttl` - how long the item stays live
+    pub fn add_with_ttl(&mut self, item: Rc<RefCell<T>>, ttl: Duration) {
+        self.list.add_raw(Entry {
+            expires_at: Instant::now() + ttl,
+            item,
+        });
+    }
+
+    /// Removes every element whose ttl has run out
+    /// #### Returns
+    /// Number of elements removed
+    pub fn purge_expired(&mut self) -> usize {
+        let now = Instant::now();
+        let expired: Vec<_> = self
+            .list
+            .handles()
+            .filter(|handle| matches!(handle.content(), Ok(e) if e.borrow().expires_at <= now))
+            .collect();
+        for handle in &expired {
+            let _ = self.list.remove_handle(handle);
+        }
+        expired.len()
+    }
+
+    /// Purges expired elements
+    /// #### Returns
+    /// an iterator over the live elements, in insertion order
+    pub fn iter_snapshot(&mut self) -> SnapshotIterator<T> {
+        self.purge_expired();
+        let contents = self
+            .list
+            .iter_snapshot()
+            .map(|entry| entry.borrow().item.clone())
+            .collect();
+        SnapshotIterator::new(contents)
+    }
+
+    /// Purges expired elements
+    /// #### Returns
+    /// Number of live elements
+    pub fn size(&mut self) -> usize {
+        self.purge_expired();
+        self.list.size()
+    }
+
+    /// Purges expired elements
+    /// #### Returns
+    /// `true` if no element is live
+    pub fn is_empty(&mut self) -> bool {
+        self.size() < 1
+    }
+
+    /// #### Returns
+    /// how long an element added with `add` stays live
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+}
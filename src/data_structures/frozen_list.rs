@@ -0,0 +1,128 @@
+use super::linked_list::{List, ListOperationErr};
+use std::{cell::RefCell, rc::Rc};
+
+/// ### Summary
+/// A read-only view of a list, produced by `freeze()`.
+///
+/// Only traversal and query operations are exposed, so components receiving a
+/// `FrozenList` cannot add or remove elements. Cloning only bumps a reference count.
+///
+/// `FrozenList` does not implement `List`, so none of these compile:
+/// ```compile_fail
+/// use collections_test::data_structures::linked_list::{LinkedList, List};
+/// let mut frozen = LinkedList::<i32>::new().freeze();
+/// frozen.add_raw(1);
+/// ```
+/// ```compile_fail
+/// use collections_test::data_structures::linked_list::{LinkedList, List};
+/// let mut frozen = LinkedList::<i32>::new().freeze();
+/// frozen.insert_raw_at(1, 0);
+/// ```
+/// ```compile_fail
+/// use collections_test::data_structures::linked_list::{LinkedList, List};
+/// let mut frozen = LinkedList::<i32>::new().freeze();
+/// frozen.remove_at(0);
+/// ```
+#[derive(Debug)]
+pub struct FrozenList<L> {
+    list: Rc<L>,
+}
+
+impl<L> Clone for FrozenList<L> {
+    fn clone(&self) -> Self {
+        FrozenList {
+            list: self.list.clone(),
+        }
+    }
+}
+
+impl<L> FrozenList<L> {
+    pub(crate) fn new(list: L) -> Self {
+        FrozenList {
+            list: Rc::new(list),
+        }
+    }
+
+    /// get a reference to the item at the specified index
+    /// #### Params
+    /// - `index` - the index to lookup
+    pub fn get<T>(&self, index: usize) -> Result<Rc<RefCell<T>>, ListOperationErr>
+    where
+        L: List<T>,
+    {
+        self.list.get(index)
+    }
+
+    /// checks whether `item` is in the list
+    /// #### Params
+    /// - `item` - the item to lookup
+    pub fn contains<T>(&self, item: Rc<RefCell<T>>) -> bool
+    where
+        L: List<T>,
+    {
+        self.list.contains(item)
+    }
+
+    /// #### Returns
+    /// `true` if the list is empty
+    pub fn is_empty<T>(&self) -> bool
+    where
+        L: List<T>,
+    {
+        self.list.is_empty()
+    }
+
+    /// #### Returns
+    /// Number of elements in list
+    pub fn size<T>(&self) -> usize
+    where
+        L: List<T>,
+    {
+        self.list.size()
+    }
+
+    /// #### Returns
+    /// an iterator over the elements of the list
+    pub fn iter(&self) -> <&L as IntoIterator>::IntoIter
+    where
+        for<'a> &'a L: IntoIterator,
+    {
+        (&*self.list).into_iter()
+    }
+
+    /// Turns the frozen list back into a modifiable list.
+    ///
+    /// The list is only copied if other clones of this `FrozenList` are still alive.
+    pub fn thaw(self) -> L
+    where
+        L: Clone,
+    {
+        Rc::try_unwrap(self.list).unwrap_or_else(|list| (*list).clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_structures::linked_list::LinkedList;
+
+    #[test]
+    fn iterating_by_reference_yields_the_original_elements() {
+        let items: Vec<_> = (0..4).map(|i| Rc::new(RefCell::new(i))).collect();
+        let frozen = items.iter().cloned().collect::<LinkedList<i32>>().freeze();
+        let view = frozen.clone();
+
+        for _ in 0..2 {
+            let yielded: Vec<_> = frozen.iter().collect();
+            assert_eq!(yielded.len(), items.len());
+            for (item, original) in yielded.iter().zip(&items) {
+                assert!(Rc::ptr_eq(item, original));
+            }
+        }
+        assert!(view
+            .iter()
+            .zip(&items)
+            .all(|(item, original)| Rc::ptr_eq(item, original)));
+        assert_eq!(frozen.size(), 4);
+    }
+}
@@ -0,0 +1,114 @@
+//! `List<T>` for `im::Vector`, enabled by the `im` feature.
+//!
+//! The vector holds the same `Rc<RefCell<T>>` elements as the crate's lists, so
+//! `contains`/`remove` keep matching elements by identity and converting between
+//! the two never copies a `T`.
+
+use super::linked_list::{LinkedList, List, ListOperationErr};
+use super::linked_list2::LinkedList2;
+use std::{cell::RefCell, rc::Rc};
+
+impl<T> List<T> for im::Vector<Rc<RefCell<T>>> {
+    fn add(&mut self, item: Rc<RefCell<T>>) {
+        self.push_back(item);
+    }
+
+    fn add_raw(&mut self, item: T) {
+        self.add(Rc::new(RefCell::new(item)));
+    }
+
+    fn insert_at(&mut self, item: Rc<RefCell<T>>, index: usize) -> Result<(), ListOperationErr> {
+        if index > self.len() {
+            return Err(ListOperationErr::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        self.insert(index, item);
+        Ok(())
+    }
+
+    fn insert_raw_at(&mut self, item: T, index: usize) -> Result<(), ListOperationErr> {
+        self.insert_at(Rc::new(RefCell::new(item)), index)
+    }
+
+    fn get(&self, index: usize) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        im::Vector::get(self, index)
+            .cloned()
+            .ok_or(ListOperationErr::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            })
+    }
+
+    fn remove(&mut self, item: Rc<RefCell<T>>) -> Result<(), ListOperationErr> {
+        if self.is_empty() {
+            return Err(ListOperationErr::OperationOnEmptyList);
+        }
+
+        let index = self
+            .iter()
+            .position(|i| Rc::ptr_eq(i, &item))
+            .ok_or(ListOperationErr::ElementNotFound)?;
+        im::Vector::remove(self, index);
+        Ok(())
+    }
+
+    fn remove_at(&mut self, index: usize) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        if index >= self.len() {
+            return Err(ListOperationErr::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        Ok(im::Vector::remove(self, index))
+    }
+
+    fn contains(&self, item: Rc<RefCell<T>>) -> bool {
+        self.iter().any(|i| Rc::ptr_eq(i, &item))
+    }
+
+    fn is_empty(&self) -> bool {
+        im::Vector::is_empty(self)
+    }
+
+    fn size(&self) -> usize {
+        im::Vector::len(self)
+    }
+
+    fn clear(&mut self) {
+        im::Vector::clear(self);
+    }
+
+    fn first(&self) -> Option<Rc<RefCell<T>>> {
+        self.front().cloned()
+    }
+
+    fn last(&self) -> Option<Rc<RefCell<T>>> {
+        self.back().cloned()
+    }
+}
+
+impl<T> From<LinkedList<T>> for im::Vector<Rc<RefCell<T>>> {
+    fn from(list: LinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T: std::fmt::Debug> From<LinkedList2<T>> for im::Vector<Rc<RefCell<T>>> {
+    fn from(list: LinkedList2<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> From<im::Vector<Rc<RefCell<T>>>> for LinkedList<T> {
+    fn from(vector: im::Vector<Rc<RefCell<T>>>) -> Self {
+        LinkedList::from_contents(vector.into_iter().collect())
+    }
+}
+
+impl<T: std::fmt::Debug> From<im::Vector<Rc<RefCell<T>>>> for LinkedList2<T> {
+    fn from(vector: im::Vector<Rc<RefCell<T>>>) -> Self {
+        LinkedList2::from_contents(vector.into_iter().collect())
+    }
+}
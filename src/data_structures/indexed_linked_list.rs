@@ -0,0 +1,607 @@
+use super::linked_list::{fmt_elements, List, ListOperationErr, SnapshotIterator};
+use std::{cell::RefCell, fmt, rc::Rc};
+
+/// Marks a missing link, so links fit in a `u32`
+const NIL: u32 = u32::MAX;
+
+struct Slot<T> {
+    /// `None` while the slot is on the free list
+    content: Option<Rc<RefCell<T>>>,
+    prev: u32,
+    /// the next node, or the next free slot while the slot is on the free list
+    next: u32,
+}
+
+/// ### Summary
+/// A doubly linked list whose nodes live in a single `Vec` (a slab) and link to each other
+/// by `u32` slot indices instead of `Rc`s.
+///
+/// Nodes are not allocated one by one, so a list built in order is laid out in order and
+/// walking it stays within a few cache lines. Slots of removed nodes are kept on a free list
+/// and reused by the next insertion; `compact()` lays the nodes out in list order again after
+/// many insertions and removals in the middle.
+pub struct IndexedLinkedList<T> {
+    slots: Vec<Slot<T>>,
+    head: u32,
+    tail: u32,
+    /// the first free slot, the rest of the free list is linked through `next`
+    free: u32,
+    size: usize,
+}
+
+impl<T> IndexedLinkedList<T> {
+    /// Constructs an empty `IndexedLinkedList<T>`
+    pub const fn new() -> Self {
+        IndexedLinkedList {
+            slots: Vec::new(),
+            head: NIL,
+            tail: NIL,
+            free: NIL,
+            size: 0,
+        }
+    }
+
+    /// Constructs an empty `IndexedLinkedList<T>` with room for `capacity` nodes
+    /// #### Params
+    /// - `capacity` - the number of nodes to allocate slots for up front
+    pub fn with_capacity(capacity: usize) -> Self {
+        IndexedLinkedList {
+            slots: Vec::with_capacity(capacity),
+            ..Self::new()
+        }
+    }
+
+    /// #### Returns
+    /// the number of nodes the list can hold without reallocating its slab
+    pub fn capacity(&self) -> usize {
+        self.slots.capacity()
+    }
+
+    /// Check index bounds
+    pub fn index_check(&self, index: usize) -> Result<(), ListOperationErr> {
+        if self.size <= index {
+            Err(ListOperationErr::IndexOutOfBounds {
+                index,
+                len: self.size,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// #### Returns
+    /// a reference to the first element, without walking the list
+    pub fn front(&self) -> Option<Rc<RefCell<T>>> {
+        self.content(self.head).cloned()
+    }
+
+    /// #### Returns
+    /// a reference to the last element, without walking the list
+    pub fn back(&self) -> Option<Rc<RefCell<T>>> {
+        self.content(self.tail).cloned()
+    }
+
+    /// add an item to the front of the list in O(1)
+    /// #### Params
+    /// - `item` - a reference to the item to add
+    pub fn push_front(&mut self, item: Rc<RefCell<T>>) {
+        trace_op!(
+            list = "IndexedLinkedList",
+            op = "push_front",
+            size = self.size
+        );
+        self.link_between(item, NIL, self.head);
+    }
+
+    /// add an item to the end of the list in O(1), same as `add`
+    /// #### Params
+    /// - `item` - a reference to the item to add
+    pub fn push_back(&mut self, item: Rc<RefCell<T>>) {
+        self.add(item);
+    }
+
+    /// Removes the first element of the list
+    pub fn shift(&mut self) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        trace_op!(list = "IndexedLinkedList", op = "shift", size = self.size);
+        if self.is_empty() {
+            return Err(ListOperationErr::OperationOnEmptyList);
+        }
+        self.unlink(self.head)
+    }
+
+    /// Removes the last element of the list
+    pub fn pop(&mut self) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        trace_op!(list = "IndexedLinkedList", op = "pop", size = self.size);
+        if self.is_empty() {
+            return Err(ListOperationErr::OperationOnEmptyList);
+        }
+        self.unlink(self.tail)
+    }
+
+    /// #### Returns
+    /// an iterator over references to the elements, without consuming the list
+    pub fn iter(&self) -> IndexedIter<'_, T> {
+        IndexedIter {
+            list: self,
+            front: self.head,
+            back: self.tail,
+            remaining: self.size,
+        }
+    }
+
+    /// #### Returns
+    /// an iterator over the elements the list holds right now
+    pub fn iter_snapshot(&self) -> SnapshotIterator<T> {
+        SnapshotIterator::new(self.iter().cloned().collect())
+    }
+
+    /// Removes every element, keeping the slab allocated for reuse
+    pub fn clear(&mut self) {
+        self.slots.clear();
+        self.head = NIL;
+        self.tail = NIL;
+        self.free = NIL;
+        self.size = 0;
+    }
+
+    /// Moves the nodes to the start of the slab in list order and releases the free slots,
+    /// so walking the list reads the slab front to back again
+    pub fn compact(&mut self) {
+        trace_op!(list = "IndexedLinkedList", op = "compact", size = self.size);
+        touch_nodes!(self.size);
+        let mut contents = Vec::with_capacity(self.size);
+        let mut cur = self.head;
+        while cur != NIL {
+            let slot = &mut self.slots[cur as usize];
+            contents.extend(slot.content.take());
+            cur = slot.next;
+        }
+
+        self.clear();
+        for content in contents {
+            self.link_between(content, self.tail, NIL);
+        }
+        self.slots.shrink_to_fit();
+    }
+
+    /// #### Returns
+    /// the element held by the node in `slot`, `None` for `NIL`
+    fn content(&self, slot: u32) -> Option<&Rc<RefCell<T>>> {
+        self.slots.get(slot as usize)?.content.as_ref()
+    }
+
+    /// Walks to the node at `index` from whichever end is closer
+    /// #### Returns
+    /// the slot of the node
+    fn slot_at(&self, index: usize) -> Result<u32, ListOperationErr> {
+        self.index_check(index)?;
+
+        let from_tail = self.size - 1 - index;
+        let mut cur;
+        if index <= from_tail {
+            touch_nodes!(index + 1);
+            cur = self.head;
+            for _ in 0..index {
+                cur = self.slots[cur as usize].next;
+            }
+        } else {
+            touch_nodes!(from_tail + 1);
+            cur = self.tail;
+            for _ in 0..from_tail {
+                cur = self.slots[cur as usize].prev;
+            }
+        }
+        Ok(cur)
+    }
+
+    /// #### Returns
+    /// the slot of the node holding `item`, matched by identity
+    fn slot_of(&self, item: &Rc<RefCell<T>>) -> Option<u32> {
+        let mut cur = self.head;
+        while cur != NIL {
+            let slot = &self.slots[cur as usize];
+            if slot.content.as_ref().is_some_and(|c| Rc::ptr_eq(c, item)) {
+                return Some(cur);
+            }
+            cur = slot.next;
+        }
+        None
+    }
+
+    /// Links a new node holding `item` between the nodes in `prev` and `next`,
+    /// either of which may be `NIL` at the ends of the list
+    fn link_between(&mut self, item: Rc<RefCell<T>>, prev: u32, next: u32) {
+        touch_nodes!(3);
+        let slot = Slot {
+            content: Some(item),
+            prev,
+            next,
+        };
+        let new = match self.free {
+            NIL => {
+                assert!(self.slots.len() < NIL as usize, "IndexedLinkedList is full");
+                self.slots.push(slot);
+                (self.slots.len() - 1) as u32
+            }
+            free => {
+                self.free = self.slots[free as usize].next;
+                self.slots[free as usize] = slot;
+                free
+            }
+        };
+
+        match prev {
+            NIL => self.head = new,
+            prev => self.slots[prev as usize].next = new,
+        }
+        match next {
+            NIL => self.tail = new,
+            next => self.slots[next as usize].prev = new,
+        }
+        self.size += 1;
+    }
+
+    /// Unlinks the node in `slot` and puts the slot on the free list
+    /// #### Returns
+    /// a reference to the item held by the unlinked node
+    fn unlink(&mut self, slot: u32) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        touch_nodes!(3);
+        let node = &mut self.slots[slot as usize];
+        let Some(content) = node.content.take() else {
+            unreachable!("only free slots have no content");
+        };
+        let (prev, next) = (node.prev, node.next);
+        node.next = self.free;
+        self.free = slot;
+
+        match prev {
+            NIL => self.head = next,
+            prev => self.slots[prev as usize].next = next,
+        }
+        match next {
+            NIL => self.tail = prev,
+            next => self.slots[next as usize].prev = prev,
+        }
+        self.size -= 1;
+        Ok(content)
+    }
+}
+
+impl<T> Default for IndexedLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for IndexedLinkedList<T> {
+    fn clone(&self) -> Self {
+        let mut clone = IndexedLinkedList::with_capacity(self.size);
+        for content in self.iter() {
+            clone.add(content.clone());
+        }
+        clone
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for IndexedLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_elements(f, self.size, self.iter(), T::fmt)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for IndexedLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_elements(f, self.size, self.iter(), T::fmt)
+    }
+}
+
+/// Iterator over references to the elements of an `IndexedLinkedList`,
+/// see `IndexedLinkedList::iter`
+pub struct IndexedIter<'a, T> {
+    list: &'a IndexedLinkedList<T>,
+    front: u32,
+    back: u32,
+    /// elements between `front` and `back`, so the ends stop when they meet
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for IndexedIter<'a, T> {
+    type Item = &'a Rc<RefCell<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let slot = self.list.slots.get(self.front as usize)?;
+        self.front = slot.next;
+        self.remaining -= 1;
+        slot.content.as_ref()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IndexedIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let slot = self.list.slots.get(self.back as usize)?;
+        self.back = slot.prev;
+        self.remaining -= 1;
+        slot.content.as_ref()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IndexedIter<'a, T> {}
+
+/// Iterator moving the elements out of an `IndexedLinkedList`, unlinking each one as it is yielded
+pub struct IndexedIntoIter<T> {
+    list: IndexedLinkedList<T>,
+}
+
+impl<T> Iterator for IndexedIntoIter<T> {
+    type Item = Rc<RefCell<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.shift().ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.size, Some(self.list.size))
+    }
+}
+
+impl<T> DoubleEndedIterator for IndexedIntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop().ok()
+    }
+}
+
+impl<T> ExactSizeIterator for IndexedIntoIter<T> {}
+
+impl<T> IntoIterator for IndexedLinkedList<T> {
+    type Item = Rc<RefCell<T>>;
+
+    type IntoIter = IndexedIntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IndexedIntoIter { list: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a IndexedLinkedList<T> {
+    type Item = &'a Rc<RefCell<T>>;
+
+    type IntoIter = IndexedIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> List<T> for IndexedLinkedList<T> {
+    fn add(&mut self, item: Rc<RefCell<T>>) {
+        trace_op!(list = "IndexedLinkedList", op = "add", size = self.size);
+        self.link_between(item, self.tail, NIL);
+    }
+
+    fn add_raw(&mut self, item: T) {
+        self.add(Rc::new(RefCell::new(item)));
+    }
+
+    fn insert_at(&mut self, item: Rc<RefCell<T>>, index: usize) -> Result<(), ListOperationErr> {
+        trace_op!(
+            list = "IndexedLinkedList",
+            op = "insert_at",
+            index,
+            size = self.size
+        );
+        if index == self.size {
+            self.add(item);
+            return Ok(());
+        }
+        let next = self.slot_at(index)?;
+        let prev = self.slots[next as usize].prev;
+        self.link_between(item, prev, next);
+        Ok(())
+    }
+
+    fn insert_raw_at(&mut self, item: T, index: usize) -> Result<(), ListOperationErr> {
+        self.insert_at(Rc::new(RefCell::new(item)), index)
+    }
+
+    fn get(&self, index: usize) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        let slot = self.slot_at(index)?;
+        let Some(content) = self.content(slot) else {
+            unreachable!("only free slots have no content");
+        };
+        Ok(content.clone())
+    }
+
+    fn remove(&mut self, item: Rc<RefCell<T>>) -> Result<(), ListOperationErr> {
+        trace_op!(list = "IndexedLinkedList", op = "remove", size = self.size);
+        if self.is_empty() {
+            return Err(ListOperationErr::OperationOnEmptyList);
+        }
+
+        let slot = self
+            .slot_of(&item)
+            .ok_or(ListOperationErr::ElementNotFound)?;
+        self.unlink(slot)?;
+        Ok(())
+    }
+
+    fn remove_at(&mut self, index: usize) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        trace_op!(
+            list = "IndexedLinkedList",
+            op = "remove_at",
+            index,
+            size = self.size
+        );
+        let slot = self.slot_at(index)?;
+        self.unlink(slot)
+    }
+
+    fn contains(&self, item: Rc<RefCell<T>>) -> bool {
+        self.slot_of(&item).is_some()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.size < 1
+    }
+
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn clear(&mut self) {
+        IndexedLinkedList::clear(self);
+    }
+
+    fn first(&self) -> Option<Rc<RefCell<T>>> {
+        self.front()
+    }
+
+    fn last(&self) -> Option<Rc<RefCell<T>>> {
+        self.back()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list_of(items: std::ops::Range<i32>) -> IndexedLinkedList<i32> {
+        let mut list = IndexedLinkedList::new();
+        for i in items {
+            list.add_raw(i);
+        }
+        list
+    }
+
+    fn values(list: &IndexedLinkedList<i32>) -> Vec<i32> {
+        list.iter().map(|item| *item.borrow()).collect()
+    }
+
+    fn out_of_bounds(index: usize, len: usize) -> ListOperationErr {
+        ListOperationErr::IndexOutOfBounds { index, len }
+    }
+
+    /// Lengths covering empty, single element and longer lists
+    const LENGTHS: [usize; 3] = [0, 1, 6];
+
+    /// Checks that the links agree in both directions and every slot is either linked or free
+    fn check_links(list: &IndexedLinkedList<i32>) {
+        let mut forward = Vec::new();
+        let (mut prev, mut cur) = (NIL, list.head);
+        while cur != NIL {
+            let slot = &list.slots[cur as usize];
+            assert!(slot.content.is_some());
+            assert_eq!(slot.prev, prev);
+            forward.push(cur);
+            prev = cur;
+            cur = slot.next;
+        }
+        assert_eq!(list.tail, prev);
+        assert_eq!(forward.len(), list.size);
+
+        let mut free = 0;
+        let mut cur = list.free;
+        while cur != NIL {
+            assert!(list.slots[cur as usize].content.is_none());
+            free += 1;
+            cur = list.slots[cur as usize].next;
+        }
+        assert_eq!(forward.len() + free, list.slots.len());
+    }
+
+    #[test]
+    fn get_checks_every_index() {
+        for len in LENGTHS {
+            let list = list_of(0..len as i32);
+            for index in 0..len + 2 {
+                match list.get(index) {
+                    Ok(item) if index < len => assert_eq!(*item.borrow(), index as i32),
+                    result => assert_eq!(result.unwrap_err(), out_of_bounds(index, len)),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn insert_at_checks_every_index() {
+        for len in LENGTHS {
+            for index in 0..len + 2 {
+                let mut list = list_of(0..len as i32);
+                let mut model: Vec<i32> = (0..len as i32).collect();
+                let result = list.insert_at(Rc::new(RefCell::new(-1)), index);
+                if index <= len {
+                    result.unwrap();
+                    model.insert(index, -1);
+                } else {
+                    assert_eq!(result.unwrap_err(), out_of_bounds(index, len));
+                }
+                assert_eq!(values(&list), model);
+                check_links(&list);
+            }
+        }
+    }
+
+    #[test]
+    fn remove_at_checks_every_index() {
+        for len in LENGTHS {
+            for index in 0..len + 2 {
+                let mut list = list_of(0..len as i32);
+                let mut model: Vec<i32> = (0..len as i32).collect();
+                match list.remove_at(index) {
+                    Ok(item) if index < len => assert_eq!(*item.borrow(), model.remove(index)),
+                    result => assert_eq!(result.unwrap_err(), out_of_bounds(index, len)),
+                }
+                assert_eq!(values(&list), model);
+                check_links(&list);
+            }
+        }
+    }
+
+    #[test]
+    fn remove_matches_elements_by_identity() {
+        let mut list = IndexedLinkedList::new();
+        assert_eq!(
+            list.remove(Rc::new(RefCell::new(0))).unwrap_err(),
+            ListOperationErr::OperationOnEmptyList
+        );
+
+        let items: Vec<_> = (0..6).map(|i| Rc::new(RefCell::new(i))).collect();
+        for item in &items {
+            list.add(item.clone());
+        }
+        let mut model: Vec<i32> = (0..6).collect();
+        for i in [3, 0, 5] {
+            list.remove(items[i].clone()).unwrap();
+            model.retain(|value| *value != i as i32);
+            assert_eq!(values(&list), model);
+            check_links(&list);
+            assert_eq!(
+                list.remove(items[i].clone()).unwrap_err(),
+                ListOperationErr::ElementNotFound
+            );
+        }
+        // an equal value in another cell is not the element
+        assert_eq!(
+            list.remove(Rc::new(RefCell::new(1))).unwrap_err(),
+            ListOperationErr::ElementNotFound
+        );
+
+        // freed slots are reused by the next insertions
+        let slots = list.slots.len();
+        for i in 0..3 {
+            list.insert_raw_at(10 + i, 1).unwrap();
+        }
+        assert_eq!(list.slots.len(), slots);
+        assert_eq!(values(&list), [1, 12, 11, 10, 2, 4]);
+        check_links(&list);
+    }
+}
@@ -0,0 +1,33 @@
+//! Per-thread counters of the work done by list operations, enabled by the `instrument` feature.
+//!
+//! The lists are not `Send`, so every list only ever bumps the counters of the thread it lives on.
+
+use std::cell::Cell;
+
+thread_local! {
+    static NODES_TOUCHED: Cell<u64> = const { Cell::new(0) };
+}
+
+/// #### Returns
+/// the number of nodes walked over or relinked on this thread since the last `reset()`
+pub fn nodes_touched() -> u64 {
+    NODES_TOUCHED.with(Cell::get)
+}
+
+/// Sets the counters of this thread back to zero
+pub fn reset() {
+    NODES_TOUCHED.with(|count| count.set(0));
+}
+
+/// Runs `f`, counting only the nodes it touches
+/// #### Returns
+/// the result of `f` and the number of nodes touched
+pub fn measure<R, F: FnOnce() -> R>(f: F) -> (R, u64) {
+    let before = nodes_touched();
+    let result = f();
+    (result, nodes_touched() - before)
+}
+
+pub(crate) fn touch(nodes: u64) {
+    NODES_TOUCHED.with(|count| count.set(count.get() + nodes));
+}
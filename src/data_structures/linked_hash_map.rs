@@ -0,0 +1,235 @@
+use super::linked_list::List;
+use super::linked_list2::{LinkedList2, LinkedList2Iter, NodeHandle};
+use std::{cell::RefCell, collections::HashMap, fmt, hash::Hash, iter::FromIterator, mem, rc::Rc};
+
+/// A key and its value, as linked in the insertion order list of a `LinkedHashMap`
+#[derive(Debug)]
+struct Entry<K, V> {
+    key: K,
+    value: Rc<RefCell<V>>,
+}
+
+/// ### Summary
+/// A hash map that remembers the order its keys were first inserted in.
+///
+/// The entries are linked in a `LinkedList2` in insertion order, and the `HashMap` keeps a
+/// handle to the node of every key, so lookups, inserts and removals are all O(1) while
+/// iteration walks the list. Inserting a key again replaces its value in place, keeping its
+/// position. Every key is stored twice, once in the map and once in its entry.
+pub struct LinkedHashMap<K: std::fmt::Debug, V: std::fmt::Debug> {
+    handles: HashMap<K, NodeHandle<Entry<K, V>>>,
+    entries: LinkedList2<Entry<K, V>>,
+}
+
+impl<K: Hash + Eq + Clone + std::fmt::Debug, V: std::fmt::Debug> LinkedHashMap<K, V> {
+    /// Constructs an empty `LinkedHashMap<K, V>`
+    pub fn new() -> Self {
+        LinkedHashMap {
+            handles: HashMap::new(),
+            entries: LinkedList2::new(),
+        }
+    }
+
+    /// Constructs an empty `LinkedHashMap<K, V>` with room for `capacity` keys in its map
+    /// #### Params
+    /// - `capacity` - the number of keys to allocate room for up front
+    pub fn with_capacity(capacity: usize) -> Self {
+        LinkedHashMap {
+            handles: HashMap::with_capacity(capacity),
+            entries: LinkedList2::new(),
+        }
+    }
+
+    /// Maps `key` to `value`, adding it after every other key if it is new
+    /// #### Params
+    /// - `key` - the key to map
+    /// - `value` - a reference to the value
+    /// #### Returns
+    /// the value `key` was mapped to before, if any
+    pub fn insert(&mut self, key: K, value: Rc<RefCell<V>>) -> Option<Rc<RefCell<V>>> {
+        if let Some(entry) = self.entry(&key) {
+            return Some(mem::replace(&mut entry.borrow_mut().value, value));
+        }
+        let handle = self.entries.add_with_handle(Rc::new(RefCell::new(Entry {
+            key: key.clone(),
+            value,
+        })));
+        self.handles.insert(key, handle);
+        None
+    }
+
+    /// Maps `key` to `value`, adding it after every other key if it is new
+    /// #### Params
+    /// - `key` - the key to map
+    /// - `value` - the value
+    /// #### Returns
+    /// the value `key` was mapped to before, if any
+    pub fn insert_raw(&mut self, key: K, value: V) -> Option<Rc<RefCell<V>>> {
+        self.insert(key, Rc::new(RefCell::new(value)))
+    }
+
+    /// #### Params
+    /// - `key` - the key to lookup
+    /// #### Returns
+    /// a reference to the value mapped to `key`, if any
+    pub fn get<Q>(&self, key: &Q) -> Option<Rc<RefCell<V>>>
+    where
+        K: std::borrow::Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        Some(self.entry(key)?.borrow().value.clone())
+    }
+
+    /// checks whether `key` is mapped to a value
+    /// #### Params
+    /// - `key` - the key to lookup
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: std::borrow::Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.handles.contains_key(key)
+    }
+
+    /// Unmaps `key`, unlinking its entry in O(1)
+    /// #### Params
+    /// - `key` - the key to remove
+    /// #### Returns
+    /// a reference to the value `key` was mapped to, if any
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<Rc<RefCell<V>>>
+    where
+        K: std::borrow::Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let handle = self.handles.remove(key)?;
+        let entry = self.entries.remove_handle(&handle).ok()?;
+        let value = entry.borrow().value.clone();
+        Some(value)
+    }
+
+    /// #### Returns
+    /// the first inserted key still in the map together with its value
+    pub fn front(&self) -> Option<(K, Rc<RefCell<V>>)> {
+        let entry = self.entries.front()?;
+        let entry = entry.borrow();
+        Some((entry.key.clone(), entry.value.clone()))
+    }
+
+    /// #### Returns
+    /// the last inserted key together with its value
+    pub fn back(&self) -> Option<(K, Rc<RefCell<V>>)> {
+        let entry = self.entries.back()?;
+        let entry = entry.borrow();
+        Some((entry.key.clone(), entry.value.clone()))
+    }
+
+    /// #### Returns
+    /// Number of keys in the map
+    pub fn len(&self) -> usize {
+        self.entries.size()
+    }
+
+    /// checks whether the map is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes every key
+    pub fn clear(&mut self) {
+        self.handles.clear();
+        self.entries.clear();
+    }
+
+    /// #### Returns
+    /// an iterator over the keys and values, in the order the keys were inserted
+    pub fn iter(&self) -> LinkedHashMapIter<'_, K, V> {
+        LinkedHashMapIter {
+            entries: self.entries.iter(),
+        }
+    }
+
+    /// Get the entry of `key` through its handle
+    fn entry<Q>(&self, key: &Q) -> Option<Rc<RefCell<Entry<K, V>>>>
+    where
+        K: std::borrow::Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        // every handle in the map points to a node linked in `entries`
+        self.handles.get(key)?.content().ok()
+    }
+}
+
+impl<K: Hash + Eq + Clone + std::fmt::Debug, V: std::fmt::Debug> Default for LinkedHashMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: std::fmt::Debug, V: std::fmt::Debug> fmt::Debug for LinkedHashMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut map = f.debug_map();
+        for entry in &self.entries {
+            let entry = entry.borrow();
+            map.entry(&entry.key, &*entry.value.borrow());
+        }
+        map.finish()
+    }
+}
+
+/// Later pairs replace the values of equal keys, keeping the position of the first
+impl<K: Hash + Eq + Clone + std::fmt::Debug, V: std::fmt::Debug> FromIterator<(K, V)>
+    for LinkedHashMap<K, V>
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = LinkedHashMap::new();
+        for (key, value) in iter {
+            map.insert_raw(key, value);
+        }
+        map
+    }
+}
+
+impl<'a, K: Hash + Eq + Clone + std::fmt::Debug, V: std::fmt::Debug> IntoIterator
+    for &'a LinkedHashMap<K, V>
+{
+    type Item = (K, Rc<RefCell<V>>);
+
+    type IntoIter = LinkedHashMapIter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the keys and values of a `LinkedHashMap` in insertion order, see
+/// `LinkedHashMap::iter`
+pub struct LinkedHashMapIter<'a, K: std::fmt::Debug, V: std::fmt::Debug> {
+    entries: LinkedList2Iter<'a, Entry<K, V>>,
+}
+
+impl<'a, K: Clone + std::fmt::Debug, V: std::fmt::Debug> Iterator for LinkedHashMapIter<'a, K, V> {
+    type Item = (K, Rc<RefCell<V>>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.entries.next()?.borrow();
+        Some((entry.key.clone(), entry.value.clone()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.entries.size_hint()
+    }
+}
+
+impl<'a, K: Clone + std::fmt::Debug, V: std::fmt::Debug> DoubleEndedIterator
+    for LinkedHashMapIter<'a, K, V>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let entry = self.entries.next_back()?.borrow();
+        Some((entry.key.clone(), entry.value.clone()))
+    }
+}
+
+impl<'a, K: Clone + std::fmt::Debug, V: std::fmt::Debug> ExactSizeIterator
+    for LinkedHashMapIter<'a, K, V>
+{
+}
@@ -1,14 +1,275 @@
-use std::{cell::RefCell, ptr, rc::Rc};
+use super::frozen_list::FrozenList;
+use super::statistics::{self, Numeric};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    convert::Infallible,
+    fmt,
+    hash::{Hash, Hasher},
+    io,
+    iter::FromIterator,
+    mem,
+    ops::{Bound, ControlFlow, Index, RangeBounds},
+    ptr::NonNull,
+    rc::Rc,
+};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ListOperationErr {
-    IndexOutOfBounds,
+    /// `index` is not a valid position in a list of `len` elements
+    IndexOutOfBounds {
+        index: usize,
+        len: usize,
+    },
+    /// the negative `index` counts back past the first of `len` elements
+    NegativeIndexOutOfBounds {
+        index: isize,
+        len: usize,
+    },
     OperationOnEmptyList,
-    UnexpectedError,
     ElementNotFound,
+    StaleHandle,
+    /// the handle points to a node of another list
+    ForeignHandle,
+    Poisoned,
+    /// the element at `index` is already borrowed elsewhere
+    BorrowConflict {
+        index: usize,
+    },
+    LengthMismatch,
+    /// the element at `index` is still referenced outside the list
+    SharedElement {
+        index: usize,
+    },
 }
 
-pub const UNEXPECTED_ERR: ListOperationErr = ListOperationErr::UnexpectedError;
+/// Result of a list operation
+pub type ListResult<T> = Result<T, ListOperationErr>;
+
+/// Broad category of a `ListOperationErr`, in the spirit of `std::io::ErrorKind`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ListErrorKind {
+    /// an index or argument was outside of what the list accepts
+    InvalidInput,
+    /// the operation needs at least one element
+    Empty,
+    /// the requested element is not in the list
+    NotFound,
+    /// a thread panicked while holding the lock of a thread-safe structure
+    Poisoned,
+    /// the element is already borrowed elsewhere
+    Busy,
+}
+
+impl ListOperationErr {
+    /// #### Returns
+    /// the broad category of the error
+    pub fn kind(&self) -> ListErrorKind {
+        match self {
+            ListOperationErr::IndexOutOfBounds { .. } => ListErrorKind::InvalidInput,
+            ListOperationErr::NegativeIndexOutOfBounds { .. } => ListErrorKind::InvalidInput,
+            ListOperationErr::OperationOnEmptyList => ListErrorKind::Empty,
+            ListOperationErr::ElementNotFound => ListErrorKind::NotFound,
+            ListOperationErr::StaleHandle => ListErrorKind::InvalidInput,
+            ListOperationErr::ForeignHandle => ListErrorKind::InvalidInput,
+            ListOperationErr::Poisoned => ListErrorKind::Poisoned,
+            ListOperationErr::BorrowConflict { .. } => ListErrorKind::Busy,
+            ListOperationErr::LengthMismatch => ListErrorKind::InvalidInput,
+            ListOperationErr::SharedElement { .. } => ListErrorKind::Busy,
+        }
+    }
+}
+
+impl fmt::Display for ListOperationErr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ListOperationErr::IndexOutOfBounds { index, len } => {
+                write!(
+                    f,
+                    "index {} is out of bounds for a list of {} elements",
+                    index, len
+                )
+            }
+            ListOperationErr::NegativeIndexOutOfBounds { index, len } => {
+                write!(
+                    f,
+                    "index {} is out of bounds for a list of {} elements, valid indices are -{}..{}",
+                    index, len, len, len
+                )
+            }
+            ListOperationErr::OperationOnEmptyList => f.write_str("the list is empty"),
+            ListOperationErr::ElementNotFound => f.write_str("the element is not in the list"),
+            ListOperationErr::StaleHandle => {
+                f.write_str("the handle points to an element that was removed")
+            }
+            ListOperationErr::ForeignHandle => {
+                f.write_str("the handle points to a node of another list")
+            }
+            ListOperationErr::Poisoned => {
+                f.write_str("a thread panicked while holding the lock of the list")
+            }
+            ListOperationErr::BorrowConflict { index } => {
+                write!(f, "the element at index {} is already borrowed", index)
+            }
+            ListOperationErr::LengthMismatch => f.write_str("the lists differ in length"),
+            ListOperationErr::SharedElement { index } => write!(
+                f,
+                "the element at index {} is still referenced outside the list",
+                index
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ListOperationErr {}
+
+impl From<ListErrorKind> for io::ErrorKind {
+    fn from(kind: ListErrorKind) -> Self {
+        match kind {
+            ListErrorKind::InvalidInput => io::ErrorKind::InvalidInput,
+            ListErrorKind::Empty => io::ErrorKind::UnexpectedEof,
+            ListErrorKind::NotFound => io::ErrorKind::NotFound,
+            ListErrorKind::Poisoned => io::ErrorKind::Other,
+            ListErrorKind::Busy => io::ErrorKind::WouldBlock,
+        }
+    }
+}
+
+impl From<ListOperationErr> for io::Error {
+    fn from(err: ListOperationErr) -> Self {
+        io::Error::new(err.kind().into(), err)
+    }
+}
+
+/// A broken structural invariant, found by `check_invariants`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvariantViolation {
+    /// only one of `head` and `tail` is set
+    DanglingEnd,
+    /// the node at `index` links to a node seen earlier in the walk
+    Cycle { index: usize },
+    /// walking from the head reached `counted` nodes, but the list stores `size`
+    SizeMismatch { size: usize, counted: usize },
+    /// `tail` links to a next node
+    TailHasNext,
+    /// the last node reached from the head is not `tail`
+    TailUnreachable,
+    /// the node at `index` does not link back to the node before it
+    BrokenBackLink { index: usize },
+}
+
+/// A shared comparison of two values
+pub type EqFn<T> = Rc<dyn Fn(&T, &T) -> bool>;
+
+/// ### Summary
+/// How `contains_with`/`remove_with` match list elements against the looked up item
+pub enum EqStrategy<T> {
+    /// the very same element (`Rc` pointer identity), like `contains`/`remove`
+    Identity,
+    /// equal values, compared with `PartialEq`
+    Value(fn(&T, &T) -> bool),
+    /// values matched by a custom comparison
+    Custom(EqFn<T>),
+}
+
+impl<T> EqStrategy<T> {
+    /// #### Returns
+    /// a strategy matching equal values
+    pub fn value() -> Self
+    where
+        T: PartialEq,
+    {
+        EqStrategy::Value(T::eq)
+    }
+
+    /// #### Params
+    /// - `eq` - returns `true` if its arguments should be considered equal
+    /// #### Returns
+    /// a strategy matching values with `eq`
+    pub fn custom<F: Fn(&T, &T) -> bool + 'static>(eq: F) -> Self {
+        EqStrategy::Custom(Rc::new(eq))
+    }
+
+    /// checks whether `a` and `b` match under this strategy
+    pub fn matches(&self, a: &Rc<RefCell<T>>, b: &Rc<RefCell<T>>) -> bool {
+        match self {
+            EqStrategy::Identity => Rc::ptr_eq(a, b),
+            EqStrategy::Value(eq) => eq(&a.borrow(), &b.borrow()),
+            EqStrategy::Custom(eq) => eq(&a.borrow(), &b.borrow()),
+        }
+    }
+}
+
+impl<T> Clone for EqStrategy<T> {
+    fn clone(&self) -> Self {
+        match self {
+            EqStrategy::Identity => EqStrategy::Identity,
+            EqStrategy::Value(eq) => EqStrategy::Value(*eq),
+            EqStrategy::Custom(eq) => EqStrategy::Custom(eq.clone()),
+        }
+    }
+}
+
+/// Turns `range` into `start..end` indices, checking them against a list of `len` elements
+pub(crate) fn resolve_range<R: RangeBounds<usize>>(
+    range: R,
+    len: usize,
+) -> Result<(usize, usize), ListOperationErr> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end + 1,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    if end > len {
+        Err(ListOperationErr::IndexOutOfBounds { index: end, len })
+    } else if start > end {
+        Err(ListOperationErr::IndexOutOfBounds { index: start, len })
+    } else {
+        Ok((start, end))
+    }
+}
+
+/// Turns a Python-style index into a plain one, counting negative indices back from `len`
+/// (`-1` being the last element); the result is not checked against `len` otherwise
+pub(crate) fn resolve_signed(index: isize, len: usize) -> Result<usize, ListOperationErr> {
+    if index >= 0 {
+        Ok(index as usize)
+    } else {
+        len.checked_sub(index.unsigned_abs())
+            .ok_or(ListOperationErr::NegativeIndexOutOfBounds { index, len })
+    }
+}
+
+/// Takes the value out of a list element, cloning it if the element is still shared
+pub(crate) fn unwrap_or_clone<T: Clone>(item: Rc<RefCell<T>>) -> T {
+    Rc::try_unwrap(item)
+        .map(RefCell::into_inner)
+        .unwrap_or_else(|item| item.borrow().clone())
+}
+
+/// Hashes and compares a list element by its value
+pub(crate) struct ValueKey<T>(pub(crate) Rc<RefCell<T>>);
+
+impl<T: Hash> Hash for ValueKey<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.borrow().hash(state)
+    }
+}
+
+impl<T: PartialEq> PartialEq for ValueKey<T> {
+    fn eq(&self, other: &Self) -> bool {
+        *self.0.borrow() == *other.0.borrow()
+    }
+}
+
+impl<T: Eq> Eq for ValueKey<T> {}
 
 #[derive(Debug, Clone)]
 struct ListNode<T> {
@@ -24,6 +285,16 @@ impl<T> ListNode<T> {
         }))
     }
 
+    /// Reads a node without tracking the borrow
+    fn peek(node: &RefCell<ListNode<T>>) -> &ListNode<T> {
+        // SAFETY: nodes are only mutably borrowed by methods holding the list by `&mut`
+        // (directly or through `LinkedListExtractIf`), so no `RefMut` to a node can exist
+        // while the list (and thus `node`) is borrowed shared. Nodes can outlive that
+        // borrow inside the `LinkedListIterator`s behind `cartesian_product` and
+        // `transpose`, but those only ever borrow them shared.
+        unsafe { &*node.as_ptr() }
+    }
+
     fn link_to(&mut self, node: Rc<RefCell<ListNode<T>>>) {
         match self.linked_node {
             Some(ref mut n) => n.clone_from(&node),
@@ -50,11 +321,13 @@ pub trait List<T>: IntoIterator + Clone {
     /// insert an item at a specific index in the list
     /// #### Params
     /// - `item` - a reference to the item to insert
+    /// - `index` - the index to insert the item at, `size` appends it
     fn insert_at(&mut self, item: Rc<RefCell<T>>, index: usize) -> Result<(), ListOperationErr>;
 
     /// insert an item at a specific index in the list
     /// #### Params
     /// - `item` - the item to insert
+    /// - `index` - the index to insert the item at, `size` appends it
     fn insert_raw_at(&mut self, item: T, index: usize) -> Result<(), ListOperationErr>;
 
     /// get a reference to the item at the specified index
@@ -62,7 +335,10 @@ pub trait List<T>: IntoIterator + Clone {
     /// - `index` - the index to lookup
     fn get(&self, index: usize) -> Result<Rc<RefCell<T>>, ListOperationErr>;
 
-    /// removes the specified `item` from the list
+    /// removes the specified `item` from the list.
+    ///
+    /// Elements are matched by `Rc` pointer identity, so an equal value held by a different
+    /// `Rc` is not found; see `remove_value` to match by value.
     /// #### Params
     /// - `item` - a reference to the item to be removed
     fn remove(&mut self, item: Rc<RefCell<T>>) -> Result<(), ListOperationErr>;
@@ -72,7 +348,10 @@ pub trait List<T>: IntoIterator + Clone {
     /// - `index` - the index of the item to remove
     fn remove_at(&mut self, index: usize) -> Result<Rc<RefCell<T>>, ListOperationErr>;
 
-    /// checks whether `item` is in the list
+    /// checks whether `item` is in the list.
+    ///
+    /// Elements are matched by `Rc` pointer identity, so an equal value held by a different
+    /// `Rc` is not found; see `contains_value` to match by value.
     /// #### Params
     /// - `item` - the item to lookup
     fn contains(&self, item: Rc<RefCell<T>>) -> bool;
@@ -84,387 +363,2236 @@ pub trait List<T>: IntoIterator + Clone {
     /// #### Returns
     /// Number of elements in list
     fn size(&self) -> usize;
+
+    /// #### Returns
+    /// Number of elements in list, same as `size`
+    fn len(&self) -> usize {
+        self.size()
+    }
+
+    /// Removes every element from the list
+    fn clear(&mut self) {
+        while self.remove_at(0).is_ok() {}
+    }
+
+    /// #### Returns
+    /// a reference to the first element, `None` if the list is empty
+    fn first(&self) -> Option<Rc<RefCell<T>>> {
+        self.get(0).ok()
+    }
+
+    /// #### Returns
+    /// a reference to the last element, `None` if the list is empty
+    fn last(&self) -> Option<Rc<RefCell<T>>> {
+        self.get(self.size().checked_sub(1)?).ok()
+    }
 }
 
-#[derive(Debug)]
+/// The index of a node passed by an earlier lookup, with the node itself
+type Position<T> = Option<(usize, NonNull<RefCell<ListNode<T>>>)>;
+
 pub struct LinkedList<T> {
     head: Option<Rc<RefCell<ListNode<T>>>>,
     tail: Option<Rc<RefCell<ListNode<T>>>>,
     size: usize,
+    /// the index of the node before the last one looked up past the head, and that node, so
+    /// the next lookup can resume from there; cleared whenever nodes are relinked
+    position: Cell<Position<T>>,
+}
+
+/// Elements shown at each end of a formatted list before its middle is elided
+const FMT_EDGE: usize = 8;
+
+/// Writes `elements` as `[a, b, c]`, eliding the middle of lists longer than `2 * FMT_EDGE`
+/// unless the alternate flag (`{:#}`, `{:#?}`) is set
+pub(crate) fn fmt_elements<'a, T: 'a, I, F>(
+    f: &mut fmt::Formatter<'_>,
+    size: usize,
+    elements: I,
+    mut fmt_element: F,
+) -> fmt::Result
+where
+    I: Iterator<Item = &'a Rc<RefCell<T>>>,
+    F: FnMut(&T, &mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    let elide = !f.alternate() && size > 2 * FMT_EDGE;
+    f.write_str("[")?;
+    for (index, element) in elements.enumerate() {
+        if elide && (FMT_EDGE..size - FMT_EDGE).contains(&index) {
+            if index == FMT_EDGE {
+                write!(f, ", ... {} more", size - 2 * FMT_EDGE)?;
+            }
+            continue;
+        }
+        if index > 0 {
+            f.write_str(", ")?;
+        }
+        match element.try_borrow() {
+            Ok(element) => fmt_element(&element, f)?,
+            Err(_) => f.write_str("<borrowed>")?,
+        }
+    }
+    f.write_str("]")
+}
+
+impl<T: fmt::Debug> fmt::Debug for LinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_elements(f, self.size, self.iter(), T::fmt)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for LinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_elements(f, self.size, self.iter(), T::fmt)
+    }
+}
+
+/// Lists are equal if they hold equal elements in the same order
+impl<T: PartialEq> PartialEq for LinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.eq_by(other, |a, b| a == b)
+    }
 }
 
+impl<T: Eq> Eq for LinkedList<T> {}
+
+/// Hashes the size followed by every element, consistent with `PartialEq`
+impl<T: Hash> Hash for LinkedList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.size.hash(state);
+        let _ = self.visit(|item| {
+            item.hash(state);
+            ControlFlow::Continue(())
+        });
+    }
+}
+
+/// A shallow copy: the clone links its own nodes, but shares every `Rc<RefCell<T>>` with this
+/// list, so changing an element through one list shows in the other. `deep_clone` copies the
+/// elements too.
 impl<T> Clone for LinkedList<T> {
     fn clone(&self) -> Self {
         let mut clone = LinkedList::new();
         let mut cur = self.head.clone();
-        loop {
-            match cur {
-                Some(c) => {
-                    clone.add(c.clone().borrow().content.clone());
-                    cur = c.borrow().linked_node.clone();
-                }
-                None => break,
-            }
+        while let Some(c) = cur {
+            clone.add(c.clone().borrow().content.clone());
+            cur = c.borrow().linked_node.clone();
         }
         clone
     }
 }
 
-impl<T> LinkedList<T> {
-    /// Constructs an empty `LinkedList<T>`
-    pub fn new() -> Self {
-        LinkedList {
-            head: None,
-            tail: None,
-            size: 0,
-        }
-    }
+/// Iterator over consecutive sublists of a `LinkedList`, see `LinkedList::chunks`
+pub struct LinkedListChunks<T> {
+    list: LinkedList<T>,
+    size: usize,
+}
 
-    /// Check index bounds
-    pub fn index_check(&self, index: usize) -> Result<(), ListOperationErr> {
-        if self.size <= index {
-            Err(ListOperationErr::IndexOutOfBounds)
-        } else {
-            Ok(())
-        }
-    }
+impl<T> Iterator for LinkedListChunks<T> {
+    type Item = LinkedList<T>;
 
-    /// Removes the first element of the list
-    pub fn shift(&mut self) -> Result<Rc<RefCell<T>>, ListOperationErr> {
-        // if head
-        match self
-            .head
-            .clone()
-            .ok_or(ListOperationErr::OperationOnEmptyList)?
-            .borrow()
-            .linked_node
-            .clone()
-        {
-            Some(n) => {
-                self.size -= 1;
-                let tmp = Some(
-                    self.head
-                        .clone()
-                        .ok_or(UNEXPECTED_ERR)?
-                        .borrow()
-                        .content
-                        .clone(),
-                );
-                self.head.replace(n.clone());
-                tmp.ok_or(UNEXPECTED_ERR)
-            }
-            None => {
-                // if list size = 1
-                // reset
-                self.size -= 1;
-                self.head.take();
-                Ok(self
-                    .tail
-                    .take()
-                    .ok_or(UNEXPECTED_ERR)?
-                    .borrow()
-                    .content
-                    .clone())
-            }
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.list.is_empty() {
+            return None;
         }
+        let rest = self.list.split_off(self.size.min(self.list.size)).ok()?;
+        Some(mem::replace(&mut self.list, rest))
     }
 
-    /// Removes the last element of the list
-    pub fn pop(&mut self) -> Result<Rc<RefCell<T>>, ListOperationErr> {
-        // if tail
-        // set node before tail node as tail
-        if self.size == 1 {
-            // if list size = 1
-            // reset
-            self.size -= 1;
-            self.head.take();
-            Ok(self
-                .tail
-                .take()
-                .ok_or(UNEXPECTED_ERR)?
-                .borrow()
-                .content
-                .clone())
-        } else {
-            self.tail.replace(self.get_node_at(self.size - 2)?);
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let chunks = self.list.size.div_ceil(self.size);
+        (chunks, Some(chunks))
+    }
+}
 
-            let n = self.tail.clone().ok_or(UNEXPECTED_ERR)?;
+impl<T> ExactSizeIterator for LinkedListChunks<T> {}
 
-            let tmp = n
-                .borrow_mut()
-                .linked_node
-                .take()
-                .ok_or(UNEXPECTED_ERR)?
-                .borrow()
-                .content
-                .clone();
-            self.size -= 1;
+/// Iterator moving the elements out of a `LinkedList`, unlinking each one as it is yielded
+pub struct LinkedListIntoIter<T> {
+    list: LinkedList<T>,
+}
 
-            Ok(tmp)
+impl<T> Clone for LinkedListIntoIter<T> {
+    fn clone(&self) -> Self {
+        Self {
+            list: self.list.clone(),
         }
     }
+}
 
-    /// Get list node at `index`
-    fn get_node_at(&self, index: usize) -> Result<Rc<RefCell<ListNode<T>>>, ListOperationErr> {
-        self.index_check(index)?;
+impl<T> Iterator for LinkedListIntoIter<T> {
+    type Item = Rc<RefCell<T>>;
 
-        let mut cur = self.head.clone();
-        for _ in 0..index {
-            cur.replace(
-                cur.clone()
-                    .ok_or(UNEXPECTED_ERR)?
-                    .borrow()
-                    .linked_node
-                    .clone()
-                    .ok_or(UNEXPECTED_ERR)?,
-            );
-        }
-        cur.ok_or(UNEXPECTED_ERR)
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.shift().ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.size, Some(self.list.size))
     }
 }
 
-pub struct LinkedListIterator<T> {
-    current: Option<Rc<RefCell<ListNode<T>>>>,
+impl<T> ExactSizeIterator for LinkedListIntoIter<T> {}
+
+/// Iterator removing the elements of a `LinkedList`, see `LinkedList::drain`
+pub struct LinkedListDrain<'a, T> {
+    list: &'a mut LinkedList<T>,
 }
 
-impl<T> Iterator for LinkedListIterator<T> {
+impl<'a, T> Iterator for LinkedListDrain<'a, T> {
     type Item = Rc<RefCell<T>>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let c = self.current.clone()?;
-        let result = Some(c.clone().borrow_mut().content.clone());
-
-        match c.borrow().linked_node.clone() {
-            Some(nxt) => {
-                // set `current.linked_node` as current
-                self.current.replace(nxt);
-            }
-            None => {
-                // set `current` to `None`
-                self.current.take();
-            }
-        };
+        self.list.shift().ok()
+    }
 
-        result
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.size, Some(self.list.size))
     }
 }
 
-impl<T> IntoIterator for LinkedList<T> {
+impl<'a, T> ExactSizeIterator for LinkedListDrain<'a, T> {}
+
+/// Iterator removing the elements of a `LinkedList` that match a predicate, see
+/// `LinkedList::extract_if`
+pub struct LinkedListExtractIf<'a, T, F: FnMut(&mut T) -> bool> {
+    list: &'a mut LinkedList<T>,
+    /// the last node kept so far, `None` while still at the head
+    prev: Option<Rc<RefCell<ListNode<T>>>>,
+    filter: F,
+}
+
+impl<'a, T, F: FnMut(&mut T) -> bool> Iterator for LinkedListExtractIf<'a, T, F> {
     type Item = Rc<RefCell<T>>;
 
-    type IntoIter = LinkedListIterator<T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let cur = match &self.prev {
+                Some(prev) => prev.borrow().linked_node.clone(),
+                None => self.list.head.clone(),
+            }?;
+            if !(self.filter)(&mut cur.borrow().content.borrow_mut()) {
+                self.prev = Some(cur);
+                continue;
+            }
 
-    fn into_iter(self) -> Self::IntoIter {
-        LinkedListIterator {
-            current: self.head.clone(),
+            self.list.forget_position();
+            let next = cur.borrow_mut().linked_node.take();
+            if next.is_none() {
+                // the tail is removed, the last node kept takes its place
+                self.list.tail = self.prev.clone();
+            }
+            match &self.prev {
+                Some(prev) => prev.borrow_mut().linked_node = next,
+                None => self.list.head = next,
+            }
+            self.list.size -= 1;
+            let content = cur.borrow().content.clone();
+            return Some(content);
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.list.size))
+    }
 }
 
-impl<T> List<T> for LinkedList<T> {
-    fn add(&mut self, item: Rc<RefCell<T>>) {
-        // init node for new item
-        let node = ListNode::new(item);
+impl<T> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        // dropping the head would drop every following node recursively and could
+        // overflow the stack on long lists
+        self.clear();
+    }
+}
 
-        match self.tail {
-            Some(ref mut tail) => {
-                // on non-empty list
-                tail.borrow_mut().link_to(node.clone());
-                tail.clone_from(&node);
-            }
-            None => {
-                // On empty, use the same node for head and tail
-                self.tail = Some(node);
-                self.head = self.tail.clone();
-            }
-        }
+impl<'a, T> Drop for LinkedListDrain<'a, T> {
+    fn drop(&mut self) {
+        while self.list.shift().is_ok() {}
+    }
+}
 
-        // increment size
-        self.size += 1;
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        LinkedList::from_contents(
+            iter.into_iter()
+                .map(|item| Rc::new(RefCell::new(item)))
+                .collect(),
+        )
     }
+}
 
-    fn add_raw(&mut self, item: T) {
-        self.add(Rc::new(RefCell::new(item)));
+impl<T> FromIterator<Rc<RefCell<T>>> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = Rc<RefCell<T>>>>(iter: I) -> Self {
+        LinkedList::from_contents(iter.into_iter().collect())
     }
+}
 
-    fn insert_at(&mut self, item: Rc<RefCell<T>>, index: usize) -> Result<(), ListOperationErr> {
-        self.index_check(index)?;
+impl<T> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.add_raw(item);
+        }
+    }
+}
 
-        if index == 0 {
-            // if head
-            self.head.replace(Rc::new(RefCell::new(ListNode {
-                content: item,
-                linked_node: self.head.clone(),
-            })));
-        } else if index == self.size - 1 {
-            // if tail
+impl<T> Extend<Rc<RefCell<T>>> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = Rc<RefCell<T>>>>(&mut self, iter: I) {
+        for item in iter {
             self.add(item);
-        } else {
-            let prev = self.get_node_at(index - 1)?;
-            let n0 = prev.borrow().linked_node.clone().ok_or(UNEXPECTED_ERR)?;
-            prev.borrow_mut().link_to(Rc::new(RefCell::new(ListNode {
-                content: item,
-                linked_node: Some(n0),
-            })));
         }
+    }
+}
 
-        Ok(())
+impl<T> From<Vec<T>> for LinkedList<T> {
+    fn from(values: Vec<T>) -> Self {
+        values.into_iter().collect()
     }
+}
 
-    fn insert_raw_at(&mut self, item: T, index: usize) -> Result<(), ListOperationErr> {
-        self.insert_at(Rc::new(RefCell::new(item)), index)
+impl<T> From<Vec<Rc<RefCell<T>>>> for LinkedList<T> {
+    fn from(contents: Vec<Rc<RefCell<T>>>) -> Self {
+        LinkedList::from_contents(contents)
     }
+}
 
-    fn get(&self, index: usize) -> Result<Rc<RefCell<T>>, ListOperationErr> {
-        self.index_check(index)?;
+impl<T: Clone> From<&[T]> for LinkedList<T> {
+    fn from(values: &[T]) -> Self {
+        values.iter().cloned().collect()
+    }
+}
 
-        let mut iter = self.clone().into_iter();
+impl<T> From<LinkedList<T>> for Vec<Rc<RefCell<T>>> {
+    fn from(list: LinkedList<T>) -> Self {
+        list.iter().cloned().collect()
+    }
+}
 
-        for _ in 0..index {
-            iter.next();
+/// Elements that are still shared elsewhere are cloned out of their `RefCell`
+impl<T: Clone> From<LinkedList<T>> for Vec<T> {
+    fn from(mut list: LinkedList<T>) -> Self {
+        let mut values = Vec::with_capacity(list.size);
+        // unlinking node by node leaves the list holding no other reference to the contents
+        while let Ok(item) = list.shift() {
+            values.push(unwrap_or_clone(item));
         }
-
-        iter.next().clone().ok_or(UNEXPECTED_ERR)
+        values
     }
+}
 
-    fn contains(&self, item: Rc<RefCell<T>>) -> bool {
-        let clone = self.clone();
-        let mut result = false;
+/// `list[index]` walks to the element like `get`, panicking if `index` is out of bounds;
+/// see `set` to replace an element
+impl<T> Index<usize> for LinkedList<T> {
+    type Output = RefCell<T>;
 
-        for i in clone {
-            if ptr::eq(item.as_ref(), i.as_ref()) {
-                result = true;
-            }
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.node_ref_at(index) {
+            Ok(node) => &ListNode::peek(node).content,
+            Err(_) => panic!(
+                "index out of bounds: the size is {} but the index is {}",
+                self.size, index
+            ),
         }
-
-        result
     }
+}
 
-    fn remove(&mut self, item: Rc<RefCell<T>>) -> Result<(), ListOperationErr> {
-        let mut cur = self.head.clone();
+/// Serializes as a plain sequence of the elements
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for LinkedList<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
 
-        // check if empty
-        if self.is_empty() {
-            Err(UNEXPECTED_ERR)
+        let mut seq = serializer.serialize_seq(Some(self.size))?;
+        for content in self.iter() {
+            seq.serialize_element(&*content.borrow())?;
         }
-        // if head
-        else if ptr::eq(
-            cur.clone().ok_or(UNEXPECTED_ERR)?.borrow().content.as_ref(),
-            item.as_ref(),
-        ) {
-            let _ = self.shift();
+        seq.end()
+    }
+}
 
-            self.size -= 1;
-            Ok(())
-        } else {
-            let prev_node;
-
-            // look for node before the node matching `item`
-            loop {
-                if ptr::eq(
-                    cur.clone()
-                        .ok_or(UNEXPECTED_ERR)?
-                        .borrow()
-                        .linked_node
-                        .clone()
-                        .ok_or(UNEXPECTED_ERR)?
-                        .borrow()
-                        .content
-                        .as_ref(),
-                    item.as_ref(),
-                ) {
-                    prev_node = Some(cur);
-                    break;
-                } else {
-                    cur.replace(
-                        cur.clone()
-                            .ok_or(UNEXPECTED_ERR)?
-                            .borrow()
-                            .linked_node
-                            .clone()
-                            .ok_or(UNEXPECTED_ERR)?,
-                    );
-                }
-            }
+/// Deserializes from a plain sequence of the elements, building fresh nodes
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for LinkedList<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<T>::deserialize(deserializer).map(|items| items.into_iter().collect())
+    }
+}
 
-            if let Some(prev_node) = prev_node {
-                // if tail
-                if ptr::eq(
-                    prev_node
-                        .clone()
-                        .ok_or(UNEXPECTED_ERR)?
-                        .borrow()
-                        .linked_node
-                        .clone()
-                        .ok_or(UNEXPECTED_ERR)?
-                        .as_ref(),
-                    self.tail.clone().ok_or(UNEXPECTED_ERR)?.as_ref(),
-                ) {
-                    self.tail.replace(prev_node.clone().ok_or(UNEXPECTED_ERR)?);
-                } else {
-                    let target_node = prev_node
-                        .clone()
-                        .ok_or(UNEXPECTED_ERR)?
-                        .borrow()
-                        .linked_node
-                        .clone();
-                    prev_node
-                        .clone()
-                        .ok_or(UNEXPECTED_ERR)?
-                        .borrow_mut()
-                        .linked_node
-                        .replace(
-                            target_node
-                                .ok_or(UNEXPECTED_ERR)?
-                                .borrow()
-                                .linked_node
-                                .clone()
-                                .ok_or(UNEXPECTED_ERR)?,
-                        );
-                }
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-                self.size -= 1;
-                Ok(())
-            } else {
-                Err(ListOperationErr::ElementNotFound)
-            }
+impl<T> LinkedList<T> {
+    /// Constructs an empty `LinkedList<T>`.
+    ///
+    /// An empty list does not allocate, so this can be used in const contexts
+    /// (e.g. a `const` item or a `thread_local!` initializer).
+    pub const fn new() -> Self {
+        LinkedList {
+            head: None,
+            tail: None,
+            size: 0,
+            position: Cell::new(None),
         }
     }
 
-    fn remove_at(&mut self, index: usize) -> Result<Rc<RefCell<T>>, ListOperationErr> {
-        self.index_check(index)?;
-
-        if index == 0 {
-            // if head
-            self.shift()
-        } else if index == self.size - 1 {
-            // if tail
-            self.pop()
-        } else {
-            // otherwise...
-            // get node before specified `index`
-            let n = self.get_node_at(index - 1)?;
-            // get node after specified `index`
-            let n_after = self.get_node_at(index)?.borrow().linked_node.clone();
-
-            self.size -= 1;
-            let result = {
-                n.borrow()
-                    .linked_node
-                    .clone()
-                    .ok_or(UNEXPECTED_ERR)?
-                    .borrow()
-                    .content
-                    .clone()
-            };
+    /// #### Returns
+    /// a reference to the first element, without walking the list
+    pub fn front(&self) -> Option<Rc<RefCell<T>>> {
+        Some(self.head.as_ref()?.borrow().content.clone())
+    }
 
-            if let Some(nxt) = n_after {
-                // link previous node to after node
-                n.borrow_mut().linked_node.replace(nxt);
+    /// #### Returns
+    /// a reference to the last element, without walking the list
+    pub fn back(&self) -> Option<Rc<RefCell<T>>> {
+        Some(self.tail.as_ref()?.borrow().content.clone())
+    }
+
+    /// Calls `f` with the first element borrowed mutably
+    /// #### Returns
+    /// the result of `f`, `None` on an empty list
+    pub fn front_mut<R, F: FnOnce(&mut T) -> R>(&mut self, f: F) -> Option<R> {
+        let head = self.head.as_ref()?.borrow();
+        let mut content = head.content.borrow_mut();
+        Some(f(&mut content))
+    }
+
+    /// Calls `f` with the last element borrowed mutably
+    /// #### Returns
+    /// the result of `f`, `None` on an empty list
+    pub fn back_mut<R, F: FnOnce(&mut T) -> R>(&mut self, f: F) -> Option<R> {
+        let tail = self.tail.as_ref()?.borrow();
+        let mut content = tail.content.borrow_mut();
+        Some(f(&mut content))
+    }
+
+    /// Walks the nodes and checks that the links agree with `head`, `tail` and `size`.
+    ///
+    /// Meant for tests and debugging: it is O(n) and keeps a set of the visited nodes.
+    /// #### Returns
+    /// the first broken invariant found
+    pub fn check_invariants(&self) -> Result<(), InvariantViolation> {
+        let tail = match (&self.head, &self.tail) {
+            (Some(_), Some(tail)) => tail,
+            (None, None) if self.size == 0 => return Ok(()),
+            (None, None) => {
+                return Err(InvariantViolation::SizeMismatch {
+                    size: self.size,
+                    counted: 0,
+                })
+            }
+            _ => return Err(InvariantViolation::DanglingEnd),
+        };
+
+        let mut visited = HashSet::new();
+        let mut last = None;
+        let mut cur = self.head.as_deref();
+        while let Some(node) = cur {
+            if !visited.insert(node as *const RefCell<ListNode<T>>) {
+                return Err(InvariantViolation::Cycle {
+                    index: visited.len(),
+                });
+            }
+            last = Some(node);
+            cur = ListNode::peek(node).linked_node.as_deref();
+        }
+
+        if ListNode::peek(tail).linked_node.is_some() {
+            return Err(InvariantViolation::TailHasNext);
+        }
+        if !last.is_some_and(|last| std::ptr::eq(last, &**tail)) {
+            return Err(InvariantViolation::TailUnreachable);
+        }
+        if visited.len() != self.size {
+            return Err(InvariantViolation::SizeMismatch {
+                size: self.size,
+                counted: visited.len(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Check index bounds
+    pub fn index_check(&self, index: usize) -> Result<(), ListOperationErr> {
+        if self.size <= index {
+            Err(ListOperationErr::IndexOutOfBounds {
+                index,
+                len: self.size,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// get a reference to the item at the specified index, counting back from the end
+    /// for negative indices (`-1` is the last element)
+    /// #### Params
+    /// - `index` - the index to lookup
+    pub fn get_signed(&self, index: isize) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        self.get(resolve_signed(index, self.size)?)
+    }
+
+    /// insert an item at a specific index, counting back from the end for negative indices,
+    /// so `-1` inserts before the last element
+    /// #### Params
+    /// - `item` - a reference to the item to insert
+    /// - `index` - the index to insert the item at, `size` appends it
+    pub fn insert_at_signed(
+        &mut self,
+        item: Rc<RefCell<T>>,
+        index: isize,
+    ) -> Result<(), ListOperationErr> {
+        let index = resolve_signed(index, self.size)?;
+        self.insert_at(item, index)
+    }
+
+    /// removes the item at the specified index, counting back from the end for negative
+    /// indices (`-1` is the last element)
+    /// #### Params
+    /// - `index` - the index of the item to remove
+    pub fn remove_at_signed(&mut self, index: isize) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        let index = resolve_signed(index, self.size)?;
+        self.remove_at(index)
+    }
+
+    /// Removes the first element of the list
+    pub fn shift(&mut self) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        trace_op!(list = "LinkedList", op = "shift", size = self.size);
+        if self.is_empty() {
+            return Err(ListOperationErr::OperationOnEmptyList);
+        }
+
+        let node = self.unlink_at(0)?;
+        let content = node.borrow().content.clone();
+        Ok(content)
+    }
+
+    /// Removes the last element of the list
+    pub fn pop(&mut self) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        trace_op!(list = "LinkedList", op = "pop", size = self.size);
+        let last = self
+            .size
+            .checked_sub(1)
+            .ok_or(ListOperationErr::OperationOnEmptyList)?;
+
+        let node = self.unlink_at(last)?;
+        let content = node.borrow().content.clone();
+        Ok(content)
+    }
+
+    /// Removes the element at `index` and takes its value out of the `Rc<RefCell<T>>`
+    /// #### Params
+    /// - `index` - the index of the element to remove
+    /// #### Returns
+    /// the owned value, or `SharedElement` if it is still referenced outside the list, in
+    /// which case the element stays in place
+    pub fn remove_at_value(&mut self, index: usize) -> Result<T, ListOperationErr> {
+        if Rc::strong_count(&ListNode::peek(self.node_ref_at(index)?).content) > 1 {
+            return Err(ListOperationErr::SharedElement { index });
+        }
+        match Rc::try_unwrap(self.remove_at(index)?) {
+            Ok(content) => Ok(content.into_inner()),
+            Err(content) => {
+                // a node kept alive outside the list still holds the element, put it back
+                self.insert_at(content, index)?;
+                Err(ListOperationErr::SharedElement { index })
+            }
+        }
+    }
+
+    /// Removes the first element of the list and takes its value, see `remove_at_value`
+    pub fn shift_value(&mut self) -> Result<T, ListOperationErr> {
+        if self.is_empty() {
+            return Err(ListOperationErr::OperationOnEmptyList);
+        }
+        self.remove_at_value(0)
+    }
+
+    /// Removes the last element of the list and takes its value, see `remove_at_value`
+    pub fn pop_value(&mut self) -> Result<T, ListOperationErr> {
+        let last = self
+            .size
+            .checked_sub(1)
+            .ok_or(ListOperationErr::OperationOnEmptyList)?;
+        self.remove_at_value(last)
+    }
+
+    /// Splits the list into its first element and the rest of the list
+    /// #### Returns
+    /// `(first_element, rest_of_list)`
+    pub fn split_first(mut self) -> Result<(Rc<RefCell<T>>, Self), ListOperationErr> {
+        let first = self.shift()?;
+        Ok((first, self))
+    }
+
+    /// Splits the list into its last element and the rest of the list
+    /// #### Returns
+    /// `(rest_of_list, last_element)`
+    pub fn split_last(mut self) -> Result<(Self, Rc<RefCell<T>>), ListOperationErr> {
+        let last = self.pop()?;
+        Ok((self, last))
+    }
+
+    /// Moves every element of `other` to the end of this list in O(1), leaving `other` empty
+    /// #### Params
+    /// - `other` - the list to move elements from
+    pub fn append(&mut self, other: &mut Self) {
+        self.forget_position();
+        other.forget_position();
+        let head = match other.head.take() {
+            Some(head) => head,
+            // nothing to move
+            None => return,
+        };
+        touch_nodes!(2);
+        match &self.tail {
+            Some(tail) => tail.borrow_mut().linked_node = Some(head),
+            None => self.head = Some(head),
+        }
+        self.tail = other.tail.take();
+        self.size += mem::take(&mut other.size);
+    }
+
+    /// Consumes both lists
+    /// #### Returns
+    /// a list with the elements of this list followed by those of `other`
+    pub fn concat(mut self, mut other: Self) -> Self {
+        self.append(&mut other);
+        self
+    }
+
+    /// Detaches the elements from `index` onward into a new list, relinking nodes in place
+    /// #### Params
+    /// - `index` - the index of the first element to move, at most the size of the list
+    /// #### Returns
+    /// a list holding the elements from `index` onward
+    pub fn split_off(&mut self, index: usize) -> Result<Self, ListOperationErr> {
+        if index > self.size {
+            return Err(ListOperationErr::IndexOutOfBounds {
+                index,
+                len: self.size,
+            });
+        }
+        if index == 0 {
+            return Ok(mem::take(self));
+        }
+
+        let last_kept = self.node_ref_at(index - 1)?.clone();
+        self.forget_position();
+        let head = last_kept.borrow_mut().linked_node.take();
+        let tail = match head {
+            Some(_) => self.tail.replace(last_kept),
+            None => None,
+        };
+        let size = self.size - index;
+        self.size = index;
+        Ok(LinkedList {
+            head,
+            tail,
+            size,
+            position: Cell::new(None),
+        })
+    }
+
+    /// Splits the list in two at `index`, relinking nodes in place
+    /// #### Params
+    /// - `index` - the index of the first element of the second list, at most the size of the list
+    /// #### Returns
+    /// `(elements_before_index, elements_from_index)`
+    pub fn split_at(mut self, index: usize) -> Result<(Self, Self), ListOperationErr> {
+        let rest = self.split_off(index)?;
+        Ok((self, rest))
+    }
+
+    /// Splits the list into consecutive lists of `size` elements, the last one possibly shorter.
+    ///
+    /// Each chunk is detached with `split_off`, so nodes are relinked rather than copied.
+    /// Panics if `size` is 0.
+    /// #### Params
+    /// - `size` - the number of elements per chunk
+    pub fn chunks(self, size: usize) -> LinkedListChunks<T> {
+        assert!(size > 0, "chunk size must be non-zero");
+        LinkedListChunks { list: self, size }
+    }
+
+    /// Builds a list from already allocated contents in one pass,
+    /// setting `head`, `tail` and `size` once at the end
+    pub(crate) fn from_contents(contents: Vec<Rc<RefCell<T>>>) -> Self {
+        let size = contents.len();
+        let mut head: Option<Rc<RefCell<ListNode<T>>>> = None;
+        let mut tail = None;
+
+        // link back to front so every node is created already pointing to its successor
+        for content in contents.into_iter().rev() {
+            let node = Rc::new(RefCell::new(ListNode {
+                content,
+                linked_node: head.take(),
+            }));
+            if tail.is_none() {
+                tail = Some(node.clone());
+            }
+            head = Some(node);
+        }
+
+        LinkedList {
+            head,
+            tail,
+            size,
+            position: Cell::new(None),
+        }
+    }
+
+    /// Borrows the element at `index` for as long as the guard lives
+    /// #### Params
+    /// - `index` - the index to lookup
+    /// #### Returns
+    /// a read guard to the element, failing with `BorrowConflict` if it is mutably borrowed elsewhere
+    pub fn borrow_at(&self, index: usize) -> Result<Ref<'_, T>, ListOperationErr> {
+        ListNode::peek(self.node_ref_at(index)?)
+            .content
+            .try_borrow()
+            .map_err(|_| ListOperationErr::BorrowConflict { index })
+    }
+
+    /// Mutably borrows the element at `index` for as long as the guard lives
+    /// #### Params
+    /// - `index` - the index to lookup
+    /// #### Returns
+    /// a write guard to the element, failing with `BorrowConflict` if it is borrowed elsewhere
+    pub fn borrow_mut_at(&mut self, index: usize) -> Result<RefMut<'_, T>, ListOperationErr> {
+        ListNode::peek(self.node_ref_at(index)?)
+            .content
+            .try_borrow_mut()
+            .map_err(|_| ListOperationErr::BorrowConflict { index })
+    }
+
+    /// Replaces the element at `index` in place, keeping its `Rc` (and anyone sharing it)
+    /// #### Params
+    /// - `index` - the index of the element to replace
+    /// - `value` - the new value
+    /// #### Returns
+    /// the previous value, failing with `BorrowConflict` if the element is borrowed elsewhere
+    pub fn set(&mut self, index: usize, value: T) -> Result<T, ListOperationErr> {
+        trace_op!(list = "LinkedList", op = "set", index, size = self.size);
+        let mut content = self.borrow_mut_at(index)?;
+        Ok(mem::replace(&mut *content, value))
+    }
+
+    /// Calls `f` with the element at `index` borrowed
+    /// #### Params
+    /// - `index` - the index to lookup
+    /// - `f` - called with the element
+    /// #### Returns
+    /// the result of `f`, failing with `BorrowConflict` if the element is mutably borrowed elsewhere
+    pub fn with<R, F: FnOnce(&T) -> R>(&self, index: usize, f: F) -> Result<R, ListOperationErr> {
+        Ok(f(&*self.borrow_at(index)?))
+    }
+
+    /// Calls `f` with the element at `index` borrowed mutably
+    /// #### Params
+    /// - `index` - the index to lookup
+    /// - `f` - called with the element
+    /// #### Returns
+    /// the result of `f`, failing with `BorrowConflict` if the element is borrowed elsewhere
+    pub fn update<R, F: FnOnce(&mut T) -> R>(
+        &mut self,
+        index: usize,
+        f: F,
+    ) -> Result<R, ListOperationErr> {
+        Ok(f(&mut *self.borrow_mut_at(index)?))
+    }
+
+    /// Exchanges the elements at `i` and `j` by swapping the contents of their nodes
+    /// #### Params
+    /// - `i` - the index of the first element
+    /// - `j` - the index of the second element
+    pub fn swap(&mut self, i: usize, j: usize) -> Result<(), ListOperationErr> {
+        trace_op!(list = "LinkedList", op = "swap", i, j, size = self.size);
+        let first = self.get_node_at(i)?;
+        let second = self.get_node_at(j)?;
+        if i != j {
+            mem::swap(
+                &mut first.borrow_mut().content,
+                &mut second.borrow_mut().content,
+            );
+        }
+        Ok(())
+    }
+
+    /// Get list node at `index`
+    fn get_node_at(&self, index: usize) -> Result<Rc<RefCell<ListNode<T>>>, ListOperationErr> {
+        self.node_ref_at(index).cloned()
+    }
+
+    /// Get a reference to the list node at `index`, borrowed from the list itself
+    fn node_ref_at(&self, index: usize) -> Result<&Rc<RefCell<ListNode<T>>>, ListOperationErr> {
+        self.index_check(index)?;
+        if index == 0 {
+            touch_nodes!(1);
+            return self
+                .head
+                .as_ref()
+                .ok_or(ListOperationErr::IndexOutOfBounds {
+                    index,
+                    len: self.size,
+                });
+        }
+
+        // the link to the node is held by the node before it: walk there, resuming from the
+        // node passed by the last lookup unless it is past that one
+        let target = index - 1;
+        let (mut cur, start) = match self.position.get() {
+            // SAFETY: the position is cleared whenever this list relinks nodes, and only this list
+            // can relink them as it hands out no handles to its nodes, so the node is still
+            // linked into this list, which keeps it alive for the borrow of `self`
+            Some((cached, node)) if cached <= target => (Some(unsafe { node.as_ref() }), cached),
+            _ => (self.head.as_deref(), 0),
+        };
+        touch_nodes!(target - start + 1);
+        for _ in start..target {
+            cur = cur.and_then(|c| ListNode::peek(c).linked_node.as_deref());
+        }
+        // the walk only comes up short if `index` is past the last node
+        let found = cur.and_then(|prev| Some((prev, ListNode::peek(prev).linked_node.as_ref()?)));
+        let (prev, link) = found.ok_or(ListOperationErr::IndexOutOfBounds {
+            index,
+            len: self.size,
+        })?;
+        self.position.set(Some((target, NonNull::from(prev))));
+        Ok(link)
+    }
+
+    /// Forgets the last node looked up, whose index or link relinking may have changed
+    fn forget_position(&mut self) {
+        *self.position.get_mut() = None;
+    }
+
+    /// Unlinks the node at `index`, keeping `head`, `tail` and `size` in sync
+    /// #### Returns
+    /// the unlinked node
+    fn unlink_at(&mut self, index: usize) -> Result<Rc<RefCell<ListNode<T>>>, ListOperationErr> {
+        self.forget_position();
+        let node =
+            if index == 0 {
+                // if head
+                touch_nodes!(1);
+                let node = self.head.take().ok_or(ListOperationErr::IndexOutOfBounds {
+                    index,
+                    len: self.size,
+                })?;
+                self.head = node.borrow_mut().linked_node.take();
+                if self.head.is_none() {
+                    // if list size = 1
+                    self.tail = None;
+                }
+                node
+            } else {
+                let prev = self.get_node_at(index - 1)?;
+                self.forget_position();
+                let node = prev.borrow_mut().linked_node.take().ok_or(
+                    ListOperationErr::IndexOutOfBounds {
+                        index,
+                        len: self.size,
+                    },
+                )?;
+                touch_nodes!(1);
+                let next = node.borrow_mut().linked_node.take();
+                if next.is_none() {
+                    // if tail
+                    self.tail = Some(prev.clone());
+                }
+                prev.borrow_mut().linked_node = next;
+                node
+            };
+
+        self.size -= 1;
+        Ok(node)
+    }
+
+    /// Links a detached node at the start of the list
+    fn link_front(&mut self, node: Rc<RefCell<ListNode<T>>>) {
+        self.forget_position();
+        touch_nodes!(1);
+        node.borrow_mut().linked_node = self.head.take();
+        if self.tail.is_none() {
+            self.tail = Some(node.clone());
+        }
+        self.head = Some(node);
+        self.size += 1;
+    }
+
+    /// add an item to the front of the list in O(1)
+    /// #### Params
+    /// - `item` - a reference to the item to add
+    pub fn push_front(&mut self, item: Rc<RefCell<T>>) {
+        trace_op!(list = "LinkedList", op = "push_front", size = self.size);
+        self.link_front(ListNode::new(item));
+    }
+
+    /// add an item to the end of the list in O(1), same as `add`
+    /// #### Params
+    /// - `item` - a reference to the item to add
+    pub fn push_back(&mut self, item: Rc<RefCell<T>>) {
+        self.add(item);
+    }
+
+    /// Links a detached node at the end of the list
+    fn link_back(&mut self, node: Rc<RefCell<ListNode<T>>>) {
+        self.forget_position();
+        touch_nodes!(2);
+        match self.tail.replace(node.clone()) {
+            // on non-empty list
+            Some(tail) => tail.borrow_mut().link_to(node),
+            // On empty, use the same node for head and tail
+            None => self.head = Some(node),
+        }
+        self.size += 1;
+    }
+
+    /// Walks the list by reference, calling `f` on every element until it breaks.
+    ///
+    /// No `Rc` is cloned along the way, making this the cheapest way to read the whole list.
+    /// #### Params
+    /// - `f` - called with each element, in order
+    /// #### Returns
+    /// `ControlFlow::Break` if `f` stopped the walk early
+    pub fn visit<F: FnMut(&T) -> ControlFlow<()>>(&self, mut f: F) -> ControlFlow<()> {
+        match self.try_visit(|item| Ok::<_, Infallible>(f(item))) {
+            Ok(ControlFlow::Break(_)) => ControlFlow::Break(()),
+            _ => ControlFlow::Continue(()),
+        }
+    }
+
+    /// Walks the list by reference like `visit`, but `f` can also break with a value
+    /// or fail, which stops the walk and hands the error back.
+    /// #### Params
+    /// - `f` - called with each element, in order
+    /// #### Returns
+    /// - `Ok(ControlFlow::Continue(()))` if every element was visited
+    /// - `Ok(ControlFlow::Break((index, value)))` if `f` broke at `index`
+    /// - `Err((index, err))` if `f` failed at `index`
+    pub fn try_visit<B, E, F: FnMut(&T) -> Result<ControlFlow<B>, E>>(
+        &self,
+        mut f: F,
+    ) -> Result<ControlFlow<(usize, B)>, (usize, E)> {
+        let mut cur = self.head.as_deref();
+        let mut index = 0;
+        while let Some(c) = cur {
+            let node = ListNode::peek(c);
+            match f(&node.content.borrow()) {
+                Ok(ControlFlow::Continue(())) => {}
+                Ok(ControlFlow::Break(value)) => return Ok(ControlFlow::Break((index, value))),
+                Err(err) => return Err((index, err)),
+            }
+            cur = node.linked_node.as_deref();
+            index += 1;
+        }
+        Ok(ControlFlow::Continue(()))
+    }
+
+    /// #### Params
+    /// - `f` - called with each element, in order
+    /// #### Returns
+    /// a new list holding the results of `f`
+    pub fn map<U, F: FnMut(&T) -> U>(&self, mut f: F) -> LinkedList<U> {
+        self.iter().map(|content| f(&content.borrow())).collect()
+    }
+
+    /// Copies the list together with its elements, unlike `clone` which shares them
+    /// #### Returns
+    /// a new list holding clones of the element values, each in a fresh cell
+    pub fn deep_clone(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.map(T::clone)
+    }
+
+    /// #### Params
+    /// - `keep` - called with each element, in order
+    /// #### Returns
+    /// a new list sharing the elements for which `keep` returns `true`
+    pub fn filter<F: FnMut(&T) -> bool>(&self, mut keep: F) -> Self {
+        self.iter()
+            .filter(|content| keep(&content.borrow()))
+            .cloned()
+            .collect()
+    }
+
+    /// Combines the elements into a single value, from the head
+    /// #### Params
+    /// - `init` - the starting value
+    /// - `f` - called with the value so far and each element, in order
+    /// #### Returns
+    /// the value returned by the last call to `f`, `init` for an empty list
+    pub fn fold<B, F: FnMut(B, &T) -> B>(&self, init: B, mut f: F) -> B {
+        self.iter()
+            .fold(init, |acc, content| f(acc, &content.borrow()))
+    }
+
+    /// Get the first element matching `predicate`
+    /// #### Params
+    /// - `predicate` - called with each element, in order, until it returns `true`
+    /// #### Returns
+    /// a reference to the matching element, `None` if there is none
+    pub fn find<F: FnMut(&T) -> bool>(&self, mut predicate: F) -> Option<Rc<RefCell<T>>> {
+        self.iter()
+            .find(|content| predicate(&content.borrow()))
+            .cloned()
+    }
+
+    /// Get the index of the first element matching `predicate`
+    /// #### Params
+    /// - `predicate` - called with each element, in order, until it returns `true`
+    pub fn position<F: FnMut(&T) -> bool>(&self, mut predicate: F) -> Option<usize> {
+        self.iter().position(|content| predicate(&content.borrow()))
+    }
+
+    /// #### Params
+    /// - `predicate` - called with each element, in order, until it returns `true`
+    /// #### Returns
+    /// `true` if any element matches `predicate`, `false` for an empty list
+    pub fn any<F: FnMut(&T) -> bool>(&self, mut predicate: F) -> bool {
+        self.iter().any(|content| predicate(&content.borrow()))
+    }
+
+    /// #### Params
+    /// - `predicate` - called with each element, in order, until it returns `false`
+    /// #### Returns
+    /// `true` if every element matches `predicate`, `true` for an empty list
+    pub fn all<F: FnMut(&T) -> bool>(&self, mut predicate: F) -> bool {
+        self.iter().all(|content| predicate(&content.borrow()))
+    }
+
+    /// Threads a state through the list, collecting every intermediate result
+    /// (e.g. prefix sums) into a new list
+    /// #### Params
+    /// - `init` - the initial state
+    /// - `f` - updates the state with an element and returns the result for that element
+    pub fn scan<S, U, F: FnMut(&mut S, &T) -> U>(&self, init: S, mut f: F) -> LinkedList<U> {
+        let mut state = init;
+        let mut results = Vec::with_capacity(self.size);
+        let _ = self.visit(|item| {
+            results.push(Rc::new(RefCell::new(f(&mut state, item))));
+            ControlFlow::Continue(())
+        });
+        LinkedList::from_contents(results)
+    }
+
+    /// Clones the elements in `range` and adds the copies to the end of the list
+    /// #### Params
+    /// - `range` - the indices of the elements to copy
+    pub fn extend_from_within<R: RangeBounds<usize>>(
+        &mut self,
+        range: R,
+    ) -> Result<(), ListOperationErr>
+    where
+        T: Clone,
+    {
+        let (start, end) = resolve_range(range, self.size)?;
+
+        let mut copies = Vec::with_capacity(end - start);
+        let mut index = 0;
+        let _ = self.visit(|item| {
+            if index >= end {
+                return ControlFlow::Break(());
+            }
+            if index >= start {
+                copies.push(item.clone());
+            }
+            index += 1;
+            ControlFlow::Continue(())
+        });
+
+        for copy in copies {
+            self.add_raw(copy);
+        }
+        Ok(())
+    }
+
+    /// Moves the element at `index` to the start of the list by relinking its node
+    /// #### Params
+    /// - `index` - the index of the element to move
+    pub fn move_to_front(&mut self, index: usize) -> Result<(), ListOperationErr> {
+        self.index_check(index)?;
+        if index == 0 {
+            return Ok(());
+        }
+
+        let node = self.unlink_at(index)?;
+        self.link_front(node);
+        Ok(())
+    }
+
+    /// Moves the element at `index` to the end of the list by relinking its node
+    /// #### Params
+    /// - `index` - the index of the element to move
+    pub fn move_to_back(&mut self, index: usize) -> Result<(), ListOperationErr> {
+        self.index_check(index)?;
+        if index == self.size - 1 {
+            return Ok(());
+        }
+
+        let node = self.unlink_at(index)?;
+        self.link_back(node);
+        Ok(())
+    }
+
+    /// Compares the list element-wise with `other`, using `eq` to compare elements
+    /// #### Params
+    /// - `other` - the list to compare against
+    /// - `eq` - returns `true` if its arguments should be considered equal
+    pub fn eq_by<U, F: FnMut(&T, &U) -> bool>(&self, other: &LinkedList<U>, mut eq: F) -> bool {
+        self.size == other.size
+            && self.cmp_by(other, |a, b| {
+                if eq(a, b) {
+                    Ordering::Equal
+                } else {
+                    Ordering::Less
+                }
+            }) == Ordering::Equal
+    }
+
+    /// Lexicographically compares the list with `other`, using `cmp` to compare elements
+    /// #### Params
+    /// - `other` - the list to compare against
+    /// - `cmp` - compares an element of this list with an element of `other`
+    pub fn cmp_by<U, F: FnMut(&T, &U) -> Ordering>(
+        &self,
+        other: &LinkedList<U>,
+        mut cmp: F,
+    ) -> Ordering {
+        let mut cur = self.head.as_deref();
+        let mut other_cur = other.head.as_deref();
+        loop {
+            match (cur, other_cur) {
+                (None, None) => return Ordering::Equal,
+                (None, Some(_)) => return Ordering::Less,
+                (Some(_), None) => return Ordering::Greater,
+                (Some(c), Some(o)) => {
+                    let (node, other_node) = (ListNode::peek(c), ListNode::peek(o));
+                    match cmp(&node.content.borrow(), &other_node.content.borrow()) {
+                        Ordering::Equal => {
+                            cur = node.linked_node.as_deref();
+                            other_cur = other_node.linked_node.as_deref();
+                        }
+                        ordering => return ordering,
+                    }
+                }
+            }
+        }
+    }
+
+    /// Encodes the list as runs of consecutive equal elements
+    /// #### Returns
+    /// a list of `(element, run_length)` pairs
+    pub fn run_length_encode(&self) -> LinkedList<(T, usize)>
+    where
+        T: PartialEq + Clone,
+    {
+        let mut runs: Vec<(T, usize)> = Vec::new();
+        let _ = self.visit(|item| {
+            match runs.last_mut() {
+                Some((value, count)) if value == item => *count += 1,
+                _ => runs.push((item.clone(), 1)),
+            }
+            ControlFlow::Continue(())
+        });
+
+        LinkedList::from_contents(
+            runs.into_iter()
+                .map(|run| Rc::new(RefCell::new(run)))
+                .collect(),
+        )
+    }
+
+    /// Expands a list of `(element, run_length)` pairs, as produced by `run_length_encode`
+    /// #### Params
+    /// - `runs` - the runs to expand
+    pub fn run_length_decode(runs: &LinkedList<(T, usize)>) -> Self
+    where
+        T: Clone,
+    {
+        let mut contents = Vec::new();
+        let _ = runs.visit(|(value, count)| {
+            contents.extend((0..*count).map(|_| Rc::new(RefCell::new(value.clone()))));
+            ControlFlow::Continue(())
+        });
+        LinkedList::from_contents(contents)
+    }
+
+    /// Builds a new list with a copy of `separator` between every pair of adjacent elements.
+    ///
+    /// The new list shares the elements of this list.
+    /// #### Params
+    /// - `separator` - the element to place between elements
+    pub fn intersperse(&self, separator: T) -> Self
+    where
+        T: Clone,
+    {
+        let mut contents = Vec::with_capacity((2 * self.size).saturating_sub(1));
+        let mut cur = self.head.as_deref();
+        while let Some(c) = cur {
+            let node = ListNode::peek(c);
+            if !contents.is_empty() {
+                contents.push(Rc::new(RefCell::new(separator.clone())));
+            }
+            contents.push(node.content.clone());
+            cur = node.linked_node.as_deref();
+        }
+        LinkedList::from_contents(contents)
+    }
+
+    /// #### Returns
+    /// how many times each distinct element occurs in the list
+    pub fn counts(&self) -> HashMap<T, usize>
+    where
+        T: Hash + Eq + Clone,
+    {
+        let mut counts = HashMap::new();
+        let _ = self.visit(|item| {
+            match counts.get_mut(item) {
+                Some(count) => *count += 1,
+                None => {
+                    counts.insert(item.clone(), 1);
+                }
+            }
+            ControlFlow::Continue(())
+        });
+        counts
+    }
+
+    /// Finds where a sorted list stops satisfying `pred`, stopping at the first failing element.
+    ///
+    /// The list is assumed to be partitioned by `pred` (all matching elements first).
+    /// #### Params
+    /// - `pred` - the predicate the list is partitioned by
+    /// #### Returns
+    /// the index of the first element for which `pred` returns `false`, or the list size
+    pub fn partition_point<P: FnMut(&T) -> bool>(&self, mut pred: P) -> usize {
+        let mut index = 0;
+        let _ = self.visit(|item| {
+            if !pred(item) {
+                return ControlFlow::Break(());
+            }
+            index += 1;
+            ControlFlow::Continue(())
+        });
+        index
+    }
+
+    /// Finds the `k`-th smallest element (counting from 0) in expected O(n), without sorting the list
+    /// #### Params
+    /// - `k` - the rank of the element to find
+    pub fn kth_smallest(&self, k: usize) -> Result<Rc<RefCell<T>>, ListOperationErr>
+    where
+        T: Ord,
+    {
+        self.index_check(k)?;
+
+        let mut contents = Vec::with_capacity(self.size);
+        let mut cur = self.head.as_deref();
+        while let Some(c) = cur {
+            let node = ListNode::peek(c);
+            contents.push(node.content.clone());
+            cur = node.linked_node.as_deref();
+        }
+
+        let (_, kth, _) = contents.select_nth_unstable_by(k, |a, b| a.borrow().cmp(&b.borrow()));
+        Ok(kth.clone())
+    }
+
+    /// #### Returns
+    /// the arithmetic mean of the elements
+    pub fn mean(&self) -> Result<f64, ListOperationErr>
+    where
+        T: Numeric,
+    {
+        statistics::mean(&self.numeric_values())
+    }
+
+    /// #### Returns
+    /// the median of the elements, found by selection rather than a full sort
+    pub fn median(&self) -> Result<f64, ListOperationErr>
+    where
+        T: Numeric,
+    {
+        statistics::median(self.numeric_values())
+    }
+
+    /// #### Returns
+    /// the population variance of the elements
+    pub fn variance(&self) -> Result<f64, ListOperationErr>
+    where
+        T: Numeric,
+    {
+        statistics::variance(&self.numeric_values())
+    }
+
+    /// Get the elements as `f64`s
+    fn numeric_values(&self) -> Vec<f64>
+    where
+        T: Numeric,
+    {
+        let mut values = Vec::with_capacity(self.size);
+        let _ = self.visit(|item| {
+            values.push(item.to_f64());
+            ControlFlow::Continue(())
+        });
+        values
+    }
+
+    /// #### Params
+    /// - `other` - the list to pair elements with
+    /// #### Returns
+    /// a lazy iterator over every `(a, b)` pair with `a` from this list and `b` from `other`
+    pub fn cartesian_product<U>(
+        &self,
+        other: &LinkedList<U>,
+    ) -> CartesianProduct<LinkedListIterator<T>, LinkedListIterator<U>> {
+        CartesianProduct::new(
+            LinkedListIterator {
+                current: self.head.clone(),
+            },
+            LinkedListIterator {
+                current: other.head.clone(),
+            },
+        )
+    }
+
+    /// Pairs up the elements of both lists in order, stopping at the end of the shorter one
+    /// like `Iterator::zip`.
+    ///
+    /// The values are moved out of their `Rc`s, only elements shared elsewhere are cloned.
+    /// #### Params
+    /// - `other` - the list to take the second element of every pair from
+    /// #### Returns
+    /// a list of `(a, b)` pairs
+    pub fn zip<U: Clone>(self, other: LinkedList<U>) -> LinkedList<(T, U)>
+    where
+        T: Clone,
+    {
+        self.into_iter()
+            .zip(other)
+            .map(|(a, b)| (unwrap_or_clone(a), unwrap_or_clone(b)))
+            .collect()
+    }
+
+    /// Picks `n` elements uniformly at random in a single pass (reservoir sampling).
+    ///
+    /// The walk follows the links rather than the stored size, and the sample
+    /// shares the elements of this list, in no particular order.
+    /// #### Params
+    /// - `n` - the number of elements to pick; the whole list is returned if it is shorter
+    /// - `rng` - the source of randomness
+    #[cfg(feature = "rand")]
+    pub fn sample<R: rand::Rng + ?Sized>(&self, n: usize, rng: &mut R) -> Self {
+        let mut reservoir = Vec::new();
+        let mut seen = 0;
+        let mut cur = self.head.as_deref();
+        while let Some(c) = cur {
+            let node = ListNode::peek(c);
+            if reservoir.len() < n {
+                reservoir.push(node.content.clone());
+            } else {
+                // keep the element with probability n / (seen + 1)
+                let slot = rng.gen_range(0..=seen);
+                if slot < n {
+                    reservoir[slot] = node.content.clone();
+                }
+            }
+            seen += 1;
+            cur = node.linked_node.as_deref();
+        }
+        Self::from_contents(reservoir)
+    }
+
+    /// Builds a new list with every distinct element of this list, followed by
+    /// the distinct elements of `other` that are not in this list.
+    ///
+    /// The new list shares the elements of both lists, keeping first occurrences in order.
+    /// #### Params
+    /// - `other` - the list to combine with
+    pub fn union(&self, other: &Self) -> Self
+    where
+        T: Hash + Eq,
+    {
+        // elements are not modified while the set is alive
+        #[allow(clippy::mutable_key_type)]
+        let mut seen = HashSet::with_capacity(self.size + other.size);
+        let contents = self
+            .iter_snapshot()
+            .chain(other.iter_snapshot())
+            .filter(|content| seen.insert(ValueKey(content.clone())))
+            .collect();
+        LinkedList::from_contents(contents)
+    }
+
+    /// Builds a new list with the distinct elements of this list that are also in `other`.
+    ///
+    /// The new list shares the elements of this list, keeping first occurrences in order.
+    /// #### Params
+    /// - `other` - the list to intersect with
+    pub fn intersection(&self, other: &Self) -> Self
+    where
+        T: Hash + Eq,
+    {
+        self.filter_distinct(other, true)
+    }
+
+    /// Builds a new list with the distinct elements of this list that are not in `other`.
+    ///
+    /// The new list shares the elements of this list, keeping first occurrences in order.
+    /// #### Params
+    /// - `other` - the list whose elements are left out
+    pub fn difference(&self, other: &Self) -> Self
+    where
+        T: Hash + Eq,
+    {
+        self.filter_distinct(other, false)
+    }
+
+    /// Collects the distinct elements of this list whose membership in `other` is `in_other`
+    fn filter_distinct(&self, other: &Self, in_other: bool) -> Self
+    where
+        T: Hash + Eq,
+    {
+        // elements are not modified while the sets are alive
+        #[allow(clippy::mutable_key_type)]
+        let other: HashSet<_> = other.iter_snapshot().map(ValueKey).collect();
+        #[allow(clippy::mutable_key_type)]
+        let mut seen = HashSet::with_capacity(self.size);
+        let contents = self
+            .iter_snapshot()
+            .map(ValueKey)
+            .filter(|key| other.contains(key) == in_other && seen.insert(ValueKey(key.0.clone())))
+            .map(|key| key.0)
+            .collect();
+        LinkedList::from_contents(contents)
+    }
+
+    /// Keeps only the elements for which `keep` returns `true`, unlinking the rest in a single pass
+    /// #### Params
+    /// - `keep` - called with each element, in order
+    /// #### Returns
+    /// Number of elements removed
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut keep: F) -> usize {
+        trace_op!(list = "LinkedList", op = "retain", size = self.size);
+        self.retain_contents(|content| keep(&content.borrow()))
+    }
+
+    /// Empties the list through an iterator that unlinks each element as it yields it.
+    ///
+    /// Elements the iterator did not get to are removed when it is dropped.
+    pub fn drain(&mut self) -> LinkedListDrain<'_, T> {
+        LinkedListDrain { list: self }
+    }
+
+    /// Unlinks the elements for which `filter` returns `true` through an iterator yielding
+    /// them, keeping the rest linked in order.
+    ///
+    /// The elements are only tested as the iterator advances: dropping it early keeps every
+    /// element it did not get to.
+    /// #### Params
+    /// - `filter` - called with each element, in order, and may change it
+    pub fn extract_if<F: FnMut(&mut T) -> bool>(
+        &mut self,
+        filter: F,
+    ) -> LinkedListExtractIf<'_, T, F> {
+        LinkedListExtractIf {
+            prev: None,
+            list: self,
+            filter,
+        }
+    }
+
+    /// Shortens the list to its first `len` elements by cutting the link after the last kept node.
+    ///
+    /// Does nothing if the list holds `len` elements or fewer.
+    /// #### Params
+    /// - `len` - the number of elements to keep
+    pub fn truncate(&mut self, len: usize) {
+        trace_op!(list = "LinkedList", op = "truncate", len, size = self.size);
+        if len < self.size {
+            // the cut off nodes are unlinked one by one when the detached list is dropped
+            drop(self.split_off(len));
+        }
+    }
+
+    /// Removes every element, unlinking the nodes one by one
+    pub fn clear(&mut self) {
+        self.forget_position();
+        trace_op!(list = "LinkedList", op = "clear", size = self.size);
+        touch_nodes!(self.size);
+        self.tail = None;
+        let mut cur = self.head.take();
+        while let Some(node) = cur {
+            cur = node.borrow_mut().linked_node.take();
+        }
+        self.size = 0;
+    }
+
+    /// Removes every element equal to an element before it, keeping first occurrences in order
+    /// #### Returns
+    /// Number of elements removed
+    pub fn unique(&mut self) -> usize
+    where
+        T: Hash + Eq,
+    {
+        // elements are not modified while the set is alive
+        #[allow(clippy::mutable_key_type)]
+        let mut seen = HashSet::with_capacity(self.size);
+        self.retain_contents(|content| seen.insert(ValueKey(content.clone())))
+    }
+
+    /// Removes consecutive equal elements, keeping the first of every run
+    /// #### Returns
+    /// Number of elements removed
+    pub fn dedup(&mut self) -> usize
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|current, previous| current == previous)
+    }
+
+    /// Removes every element for which `same(element, previous)` returns `true`, in a single pass,
+    /// where `previous` is the last element kept before it
+    /// #### Returns
+    /// Number of elements removed
+    pub fn dedup_by<F: FnMut(&T, &T) -> bool>(&mut self, mut same: F) -> usize {
+        trace_op!(list = "LinkedList", op = "dedup", size = self.size);
+        let mut previous: Option<Rc<RefCell<T>>> = None;
+        self.retain_contents(|content| {
+            let duplicate = previous
+                .as_ref()
+                .is_some_and(|previous| same(&content.borrow(), &previous.borrow()));
+            if !duplicate {
+                previous = Some(content.clone());
+            }
+            !duplicate
+        })
+    }
+
+    /// Unlinks every element for which `keep` returns `false`, in a single pass
+    /// #### Returns
+    /// Number of elements removed
+    fn retain_contents<F: FnMut(&Rc<RefCell<T>>) -> bool>(&mut self, mut keep: F) -> usize {
+        self.forget_position();
+        let mut kept = Vec::with_capacity(self.size);
+        let mut removed = 0;
+        let mut cur = self.head.clone();
+        while let Some(c) = cur {
+            cur = c.borrow_mut().linked_node.take();
+            if keep(&c.borrow().content) {
+                kept.push(c);
+            } else {
+                removed += 1;
+            }
+        }
+
+        self.relink(kept);
+        removed
+    }
+
+    /// checks whether an element matching `item` is in the list
+    /// #### Params
+    /// - `item` - the item to lookup
+    /// - `strategy` - how elements are matched against `item`
+    pub fn contains_with(&self, item: Rc<RefCell<T>>, strategy: &EqStrategy<T>) -> bool {
+        self.index_with(&item, strategy).is_some()
+    }
+
+    /// removes the first element matching `item`
+    /// #### Params
+    /// - `item` - the item to lookup
+    /// - `strategy` - how elements are matched against `item`
+    /// #### Returns
+    /// a reference to the removed element
+    pub fn remove_with(
+        &mut self,
+        item: Rc<RefCell<T>>,
+        strategy: &EqStrategy<T>,
+    ) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        let index = self
+            .index_with(&item, strategy)
+            .ok_or(ListOperationErr::ElementNotFound)?;
+        self.remove_at(index)
+    }
+
+    /// Get the index of the first element equal to `item`
+    /// #### Params
+    /// - `item` - the value to lookup
+    pub fn index_of(&self, item: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.iter().position(|content| *content.borrow() == *item)
+    }
+
+    /// checks whether an element equal to `item` is in the list
+    /// #### Params
+    /// - `item` - the value to lookup
+    pub fn contains_value(&self, item: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.index_of(item).is_some()
+    }
+
+    /// removes the first element equal to `item`
+    /// #### Params
+    /// - `item` - the value to lookup
+    /// #### Returns
+    /// a reference to the removed element
+    pub fn remove_value(&mut self, item: &T) -> Result<Rc<RefCell<T>>, ListOperationErr>
+    where
+        T: PartialEq,
+    {
+        let index = self
+            .index_of(item)
+            .ok_or(ListOperationErr::ElementNotFound)?;
+        self.remove_at(index)
+    }
+
+    /// removes every element equal to `item`, in a single pass
+    /// #### Params
+    /// - `item` - the value to remove
+    /// #### Returns
+    /// Number of elements removed
+    pub fn remove_all(&mut self, item: &T) -> usize
+    where
+        T: PartialEq,
+    {
+        trace_op!(list = "LinkedList", op = "remove_all", size = self.size);
+        self.retain_contents(|content| *content.borrow() != *item)
+    }
+
+    /// #### Params
+    /// - `item` - the value to count
+    /// #### Returns
+    /// Number of elements equal to `item`
+    pub fn count(&self, item: &T) -> usize
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(|content| *content.borrow() == *item)
+            .count()
+    }
+
+    /// Get the index of the first element matching `item`
+    fn index_with(&self, item: &Rc<RefCell<T>>, strategy: &EqStrategy<T>) -> Option<usize> {
+        self.iter()
+            .position(|content| strategy.matches(content, item))
+    }
+
+    /// Consumes the list
+    /// #### Returns
+    /// a read-only, cheaply clonable view of the list
+    pub fn freeze(self) -> FrozenList<Self> {
+        FrozenList::new(self)
+    }
+
+    /// #### Returns
+    /// an iterator over the elements the list holds right now.
+    ///
+    /// The elements are captured up front, so adding or removing elements
+    /// afterwards does not affect what the iterator yields.
+    pub fn iter_snapshot(&self) -> SnapshotIterator<T> {
+        SnapshotIterator::new(self.iter().cloned().collect())
+    }
+
+    /// #### Returns
+    /// an iterator over references to the elements, without consuming the list
+    pub fn iter(&self) -> LinkedListIter<'_, T> {
+        LinkedListIter {
+            current: self.head.as_deref(),
+            remaining: self.size,
+        }
+    }
+
+    /// #### Returns
+    /// an iterator over mutable borrows of the elements, without consuming the list.
+    ///
+    /// Panics like `RefCell::borrow_mut` if an element is borrowed elsewhere when it is reached.
+    pub fn iter_mut(&mut self) -> LinkedListIterMut<'_, T> {
+        LinkedListIterMut {
+            current: self.head.as_deref(),
+            remaining: self.size,
+        }
+    }
+
+    /// #### Returns
+    /// a `Vec<T>` with a clone of every element, in order
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().map(|item| item.borrow().clone()).collect()
+    }
+
+    /// Consumes the list, draining its elements into a sorted `Vec<T>`.
+    ///
+    /// Elements that are still shared elsewhere are cloned out of their `RefCell`.
+    pub fn into_sorted_vec(mut self) -> Vec<T>
+    where
+        T: Ord + Clone,
+    {
+        let mut values = Vec::with_capacity(self.size);
+        // unlinking node by node leaves the list holding no other reference to the contents
+        while let Ok(item) = self.shift() {
+            values.push(unwrap_or_clone(item));
+        }
+        values.sort();
+        values
+    }
+
+    /// Sorts the list in ascending order.
+    ///
+    /// The sort is a stable bottom-up merge sort that relinks the existing nodes
+    /// instead of moving their contents.
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
+
+    /// Sorts the list with a comparator function, see `sort`
+    /// #### Params
+    /// - `compare` - orders two elements
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
+        self.forget_position();
+        trace_op!(list = "LinkedList", op = "sort_by", size = self.size);
+        // bins[i] holds a sorted run of 2^i nodes (or nothing), earlier runs in higher bins
+        let mut bins: Vec<Option<Rc<RefCell<ListNode<T>>>>> = Vec::new();
+        let mut cur = self.head.take();
+        while let Some(c) = cur {
+            cur = c.borrow_mut().linked_node.take();
+            let mut run = Some(c);
+            let mut i = 0;
+            while let Some(bin) = bins.get_mut(i).and_then(Option::take) {
+                run = Self::merge_runs(Some(bin), run, &mut compare);
+                i += 1;
+            }
+            match bins.get_mut(i) {
+                Some(slot) => *slot = run,
+                None => bins.push(run),
+            }
+        }
+
+        let mut sorted = None;
+        for bin in bins.into_iter().filter(Option::is_some) {
+            sorted = Self::merge_runs(bin, sorted, &mut compare);
+        }
+        // the merges only maintain the forward links, find the new tail
+        let mut tail = sorted.clone();
+        while let Some(next) = tail.as_ref().and_then(|t| t.borrow().linked_node.clone()) {
+            tail = Some(next);
+        }
+        self.head = sorted;
+        self.tail = tail;
+    }
+
+    /// add an item at its sorted position, after any equal elements.
+    ///
+    /// The list is expected to be sorted already, see `is_sorted`.
+    /// #### Params
+    /// - `item` - a reference to the item to insert
+    /// #### Returns
+    /// the index the item was inserted at
+    pub fn insert_sorted(&mut self, item: Rc<RefCell<T>>) -> usize
+    where
+        T: Ord,
+    {
+        self.insert_sorted_by(item, T::cmp)
+    }
+
+    /// add an item at its sorted position with a comparator function, see `insert_sorted`
+    /// #### Params
+    /// - `item` - a reference to the item to insert
+    /// - `compare` - orders two elements, the list must be sorted by it
+    /// #### Returns
+    /// the index the item was inserted at
+    pub fn insert_sorted_by<F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        item: Rc<RefCell<T>>,
+        mut compare: F,
+    ) -> usize {
+        let index = self
+            .position(|content| compare(content, &item.borrow()) == Ordering::Greater)
+            .unwrap_or(self.size);
+        // `index` is at most `size`, which `insert_at` appends at
+        let _ = self.insert_at(item, index);
+        index
+    }
+
+    /// #### Returns
+    /// `true` if the elements are in ascending order, which an empty list always is
+    pub fn is_sorted(&self) -> bool
+    where
+        T: Ord,
+    {
+        self.is_sorted_by(T::cmp)
+    }
+
+    /// #### Params
+    /// - `compare` - orders two elements
+    /// #### Returns
+    /// `true` if no element is ordered after the next one by `compare`
+    pub fn is_sorted_by<F: FnMut(&T, &T) -> Ordering>(&self, mut compare: F) -> bool {
+        self.iter()
+            .zip(self.iter().skip(1))
+            .all(|(a, b)| compare(&a.borrow(), &b.borrow()) != Ordering::Greater)
+    }
+
+    /// Merges two sorted lists into one sorted list in O(n + m), relinking the nodes of both.
+    ///
+    /// The merge is stable: of equal elements, those of this list come first.
+    /// #### Params
+    /// - `other` - the sorted list to merge in
+    /// #### Returns
+    /// a sorted list holding the elements of both lists
+    pub fn merge(self, other: Self) -> Self
+    where
+        T: Ord,
+    {
+        self.merge_by(other, T::cmp)
+    }
+
+    /// Merges two lists sorted by a comparator function, see `merge`
+    /// #### Params
+    /// - `other` - the list to merge in
+    /// - `compare` - orders two elements, both lists must be sorted by it
+    pub fn merge_by<F: FnMut(&T, &T) -> Ordering>(
+        mut self,
+        mut other: Self,
+        mut compare: F,
+    ) -> Self {
+        self.forget_position();
+        other.forget_position();
+        trace_op!(
+            list = "LinkedList",
+            op = "merge_by",
+            size = self.size + other.size
+        );
+        let merged = Self::merge_runs(self.head.take(), other.head.take(), &mut compare);
+        other.tail = None;
+        self.size += mem::take(&mut other.size);
+
+        // the merges only maintain the forward links, find the new tail
+        let mut tail = merged.clone();
+        while let Some(next) = tail.as_ref().and_then(|t| t.borrow().linked_node.clone()) {
+            tail = Some(next);
+        }
+        self.head = merged;
+        self.tail = tail;
+        self
+    }
+
+    /// Sorts the list by the key `f` extracts from each element, see `sort`
+    /// #### Params
+    /// - `f` - extracts the key to sort by from an element
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut f: F) {
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Merges two sorted runs of forward-linked nodes into one, keeping `a` first on ties
+    fn merge_runs<F: FnMut(&T, &T) -> Ordering>(
+        mut a: Option<Rc<RefCell<ListNode<T>>>>,
+        mut b: Option<Rc<RefCell<ListNode<T>>>>,
+        compare: &mut F,
+    ) -> Option<Rc<RefCell<ListNode<T>>>> {
+        let mut head = None;
+        let mut tail: Option<Rc<RefCell<ListNode<T>>>> = None;
+        loop {
+            let next = match (a.take(), b.take()) {
+                (Some(x), Some(y)) => {
+                    let y_first =
+                        compare(&y.borrow().content.borrow(), &x.borrow().content.borrow())
+                            == Ordering::Less;
+                    if y_first {
+                        b = y.borrow_mut().linked_node.take();
+                        a = Some(x);
+                        y
+                    } else {
+                        a = x.borrow_mut().linked_node.take();
+                        b = Some(y);
+                        x
+                    }
+                }
+                // one run is used up, the rest of the other is already linked
+                (Some(rest), None) | (None, Some(rest)) => rest,
+                (None, None) => break,
+            };
+            match &tail {
+                Some(t) => t.borrow_mut().linked_node = Some(next.clone()),
+                None => head = Some(next.clone()),
+            }
+            tail = Some(next);
+        }
+        head
+    }
+
+    /// Sorts the list with a key extraction function, computing the key only once per element.
+    ///
+    /// The sort is stable and relinks the existing nodes instead of moving their contents.
+    /// #### Params
+    /// - `f` - extracts the key to sort by from an element
+    pub fn sort_by_cached_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut f: F) {
+        trace_op!(
+            list = "LinkedList",
+            op = "sort_by_cached_key",
+            size = self.size
+        );
+        let mut keyed = Vec::with_capacity(self.size);
+        let mut cur = self.head.clone();
+        while let Some(c) = cur {
+            let key = f(&c.borrow().content.borrow());
+            cur = c.borrow().linked_node.clone();
+            keyed.push((key, c));
+        }
+
+        keyed.sort_by(|a, b| a.0.cmp(&b.0));
+        self.relink(keyed.into_iter().map(|(_, node)| node).collect());
+    }
+
+    /// Relinks `nodes` in the given order and makes them the content of the list
+    fn relink(&mut self, nodes: Vec<Rc<RefCell<ListNode<T>>>>) {
+        self.forget_position();
+        self.head.take();
+        self.tail.take();
+        self.size = nodes.len();
+
+        for node in nodes.into_iter().rev() {
+            node.borrow_mut().linked_node = self.head.take();
+            if self.tail.is_none() {
+                self.tail = Some(node.clone());
+            }
+            self.head = Some(node);
+        }
+    }
+}
+
+impl<T> LinkedList<LinkedList<T>> {
+    /// Transposes a list of `m` rows of `n` elements into `n` rows of `m` elements,
+    /// walking all rows side by side in a single pass.
+    ///
+    /// The new rows share the elements of this list.
+    /// #### Returns
+    /// the transposed list, or `LengthMismatch` if the rows are not all the same length
+    pub fn transpose(&self) -> Result<LinkedList<LinkedList<T>>, ListOperationErr> {
+        let mut cursors = Vec::with_capacity(self.size);
+        let _ = self.visit(|row| {
+            cursors.push(LinkedListIterator {
+                current: row.head.clone(),
+            });
+            ControlFlow::Continue(())
+        });
+
+        let mut columns = Vec::new();
+        loop {
+            let column: Vec<_> = cursors.iter_mut().map(Iterator::next).collect();
+            if column.iter().all(Option::is_none) {
+                break;
+            }
+            // some rows ran out before the others
+            let column = column
+                .into_iter()
+                .collect::<Option<Vec<_>>>()
+                .ok_or(ListOperationErr::LengthMismatch)?;
+            columns.push(Rc::new(RefCell::new(LinkedList::from_contents(column))));
+        }
+
+        Ok(LinkedList::from_contents(columns))
+    }
+}
+
+impl<A: Clone, B: Clone> LinkedList<(A, B)> {
+    /// Splits a list of pairs into a list of the first and a list of the second elements,
+    /// the reverse of `zip`.
+    ///
+    /// The values are moved out of their `Rc`s, only pairs shared elsewhere are cloned.
+    /// #### Returns
+    /// `(firsts, seconds)`
+    pub fn unzip(self) -> (LinkedList<A>, LinkedList<B>) {
+        self.into_iter().map(unwrap_or_clone).unzip()
+    }
+}
+
+/// Iterator over the elements a list held when the iterator was created
+#[derive(Debug, Clone)]
+pub struct SnapshotIterator<T> {
+    contents: std::vec::IntoIter<Rc<RefCell<T>>>,
+}
+
+impl<T> SnapshotIterator<T> {
+    pub(crate) fn new(contents: Vec<Rc<RefCell<T>>>) -> Self {
+        SnapshotIterator {
+            contents: contents.into_iter(),
+        }
+    }
+}
+
+impl<T> Iterator for SnapshotIterator<T> {
+    type Item = Rc<RefCell<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.contents.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.contents.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for SnapshotIterator<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.contents.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for SnapshotIterator<T> {}
+
+/// ### Summary
+/// Lazy iterator over every `(a, b)` pair of the elements of two lists,
+/// produced by `cartesian_product()`.
+///
+/// Pairs are generated one at a time; the second list is walked again for each element of the first.
+#[derive(Debug, Clone)]
+pub struct CartesianProduct<I: Iterator, J> {
+    outer: I,
+    current: Option<I::Item>,
+    inner: J,
+    inner_start: J,
+}
+
+impl<I: Iterator, J: Clone> CartesianProduct<I, J> {
+    pub(crate) fn new(outer: I, inner: J) -> Self {
+        CartesianProduct {
+            outer,
+            current: None,
+            inner: inner.clone(),
+            inner_start: inner,
+        }
+    }
+}
+
+impl<I, J> Iterator for CartesianProduct<I, J>
+where
+    I: Iterator,
+    I::Item: Clone,
+    J: Iterator + Clone,
+{
+    type Item = (I::Item, J::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(a) = &self.current {
+                if let Some(b) = self.inner.next() {
+                    return Some((a.clone(), b));
+                }
+            }
+            // move on to the next element of the outer list and restart the inner one
+            self.current = Some(self.outer.next()?);
+            self.inner = self.inner_start.clone();
+        }
+    }
+}
+
+/// Iterator over references to the elements of a `LinkedList`, see `LinkedList::iter`
+pub struct LinkedListIter<'a, T> {
+    current: Option<&'a RefCell<ListNode<T>>>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for LinkedListIter<'a, T> {
+    type Item = &'a Rc<RefCell<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = ListNode::peek(self.current?);
+        self.current = node.linked_node.as_deref();
+        self.remaining -= 1;
+        Some(&node.content)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for LinkedListIter<'a, T> {}
+
+/// Iterator over mutable borrows of the elements of a `LinkedList`, see `LinkedList::iter_mut`
+pub struct LinkedListIterMut<'a, T> {
+    current: Option<&'a RefCell<ListNode<T>>>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for LinkedListIterMut<'a, T> {
+    type Item = RefMut<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = ListNode::peek(self.current?);
+        self.current = node.linked_node.as_deref();
+        self.remaining -= 1;
+        Some(node.content.borrow_mut())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for LinkedListIterMut<'a, T> {}
+
+pub struct LinkedListIterator<T> {
+    current: Option<Rc<RefCell<ListNode<T>>>>,
+}
+
+impl<T> Clone for LinkedListIterator<T> {
+    fn clone(&self) -> Self {
+        Self {
+            current: self.current.clone(),
+        }
+    }
+}
+
+impl<T> Iterator for LinkedListIterator<T> {
+    type Item = Rc<RefCell<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let c = self.current.clone()?;
+        let result = Some(c.borrow().content.clone());
+
+        match c.borrow().linked_node.clone() {
+            Some(nxt) => {
+                // set `current.linked_node` as current
+                self.current.replace(nxt);
+            }
+            None => {
+                // set `current` to `None`
+                self.current.take();
             }
+        };
+
+        result
+    }
+}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = Rc<RefCell<T>>;
+
+    type IntoIter = LinkedListIntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        LinkedListIntoIter { list: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LinkedList<T> {
+    type Item = &'a Rc<RefCell<T>>;
+
+    type IntoIter = LinkedListIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut LinkedList<T> {
+    type Item = RefMut<'a, T>;
+
+    type IntoIter = LinkedListIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> List<T> for LinkedList<T> {
+    fn add(&mut self, item: Rc<RefCell<T>>) {
+        trace_op!(list = "LinkedList", op = "add", size = self.size);
+        self.link_back(ListNode::new(item));
+    }
+
+    fn add_raw(&mut self, item: T) {
+        self.add(Rc::new(RefCell::new(item)));
+    }
+
+    fn insert_at(&mut self, item: Rc<RefCell<T>>, index: usize) -> Result<(), ListOperationErr> {
+        trace_op!(
+            list = "LinkedList",
+            op = "insert_at",
+            index,
+            size = self.size
+        );
+        if index > self.size {
+            return Err(ListOperationErr::IndexOutOfBounds {
+                index,
+                len: self.size,
+            });
+        }
+
+        if index == 0 {
+            // if head
+            self.link_front(ListNode::new(item));
+        } else if index == self.size {
+            self.link_back(ListNode::new(item));
+        } else {
+            // the node at `index` moves up, so the new node is never the tail
+            let prev = self.get_node_at(index - 1)?;
+            self.forget_position();
+            let next = prev.borrow_mut().linked_node.take();
+            prev.borrow_mut().link_to(Rc::new(RefCell::new(ListNode {
+                content: item,
+                linked_node: next,
+            })));
+            self.size += 1;
+        }
+
+        Ok(())
+    }
+
+    fn insert_raw_at(&mut self, item: T, index: usize) -> Result<(), ListOperationErr> {
+        self.insert_at(Rc::new(RefCell::new(item)), index)
+    }
+
+    fn get(&self, index: usize) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        let node = self.node_ref_at(index)?;
+        Ok(ListNode::peek(node).content.clone())
+    }
+
+    fn contains(&self, item: Rc<RefCell<T>>) -> bool {
+        self.index_with(&item, &EqStrategy::Identity).is_some()
+    }
 
-            Ok(result)
+    fn remove(&mut self, item: Rc<RefCell<T>>) -> Result<(), ListOperationErr> {
+        trace_op!(list = "LinkedList", op = "remove", size = self.size);
+        if self.is_empty() {
+            return Err(ListOperationErr::OperationOnEmptyList);
         }
+
+        let index = self
+            .index_with(&item, &EqStrategy::Identity)
+            .ok_or(ListOperationErr::ElementNotFound)?;
+        self.unlink_at(index)?;
+        Ok(())
+    }
+
+    fn remove_at(&mut self, index: usize) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        trace_op!(
+            list = "LinkedList",
+            op = "remove_at",
+            index,
+            size = self.size
+        );
+        self.index_check(index)?;
+
+        let node = self.unlink_at(index)?;
+        let content = node.borrow().content.clone();
+        Ok(content)
     }
 
     fn is_empty(&self) -> bool {
@@ -474,4 +2602,252 @@ impl<T> List<T> for LinkedList<T> {
     fn size(&self) -> usize {
         self.size
     }
+
+    fn clear(&mut self) {
+        LinkedList::clear(self);
+    }
+
+    fn first(&self) -> Option<Rc<RefCell<T>>> {
+        self.front()
+    }
+
+    fn last(&self) -> Option<Rc<RefCell<T>>> {
+        self.back()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list_of(items: std::ops::Range<i32>) -> LinkedList<i32> {
+        items.map(|i| Rc::new(RefCell::new(i))).collect()
+    }
+
+    fn values(list: &LinkedList<i32>) -> Vec<i32> {
+        list.to_vec()
+    }
+
+    /// Lengths covering empty, single element and longer lists
+    const LENGTHS: [usize; 3] = [0, 1, 6];
+
+    fn out_of_bounds(index: usize, len: usize) -> ListOperationErr {
+        ListOperationErr::IndexOutOfBounds { index, len }
+    }
+
+    #[test]
+    fn snapshots_ignore_later_changes() {
+        let mut list = list_of(0..4);
+        let originals: Vec<_> = list.iter().cloned().collect();
+        let mut snapshot = list.iter_snapshot();
+        assert_eq!(*snapshot.next().unwrap().borrow(), 0);
+
+        list.add_raw(4);
+        list.remove_at(1).unwrap();
+        assert_eq!(*snapshot.next().unwrap().borrow(), 1);
+        list.clear();
+        list.add_raw(5);
+
+        let rest: Vec<_> = snapshot.collect();
+        assert_eq!(rest.len(), 2);
+        assert!(rest
+            .iter()
+            .zip(&originals[2..])
+            .all(|(a, b)| Rc::ptr_eq(a, b)));
+        assert_eq!(values(&list), [5]);
+    }
+
+    #[test]
+    fn negative_index_errors_name_the_valid_range() {
+        let list = list_of(0..3);
+        assert_eq!(*list.get_signed(-3).unwrap().borrow(), 0);
+        let err = list.get_signed(-4).unwrap_err();
+        assert_eq!(
+            err,
+            ListOperationErr::NegativeIndexOutOfBounds { index: -4, len: 3 }
+        );
+        assert_eq!(
+            err.to_string(),
+            "index -4 is out of bounds for a list of 3 elements, valid indices are -3..3"
+        );
+    }
+
+    #[test]
+    fn get_checks_every_index() {
+        for len in LENGTHS {
+            let list = list_of(0..len as i32);
+            for index in 0..len + 2 {
+                match list.get(index) {
+                    Ok(item) if index < len => assert_eq!(*item.borrow(), index as i32),
+                    result => assert_eq!(result.unwrap_err(), out_of_bounds(index, len)),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn insert_at_checks_every_index() {
+        for len in LENGTHS {
+            for index in 0..len + 2 {
+                let mut list = list_of(0..len as i32);
+                let mut model: Vec<i32> = (0..len as i32).collect();
+                let result = list.insert_at(Rc::new(RefCell::new(-1)), index);
+                if index <= len {
+                    result.unwrap();
+                    model.insert(index, -1);
+                } else {
+                    assert_eq!(result.unwrap_err(), out_of_bounds(index, len));
+                }
+                assert_eq!(values(&list), model);
+                list.check_invariants().unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn remove_at_checks_every_index() {
+        for len in LENGTHS {
+            for index in 0..len + 2 {
+                let mut list = list_of(0..len as i32);
+                let mut model: Vec<i32> = (0..len as i32).collect();
+                match list.remove_at(index) {
+                    Ok(item) if index < len => assert_eq!(*item.borrow(), model.remove(index)),
+                    result => assert_eq!(result.unwrap_err(), out_of_bounds(index, len)),
+                }
+                assert_eq!(values(&list), model);
+                list.check_invariants().unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn pop_and_shift_empty_the_list() {
+        for len in LENGTHS {
+            let mut popped = list_of(0..len as i32);
+            let mut shifted = list_of(0..len as i32);
+            for i in 0..len as i32 {
+                assert_eq!(*popped.pop().unwrap().borrow(), len as i32 - 1 - i);
+                assert_eq!(*shifted.shift().unwrap().borrow(), i);
+                popped.check_invariants().unwrap();
+                shifted.check_invariants().unwrap();
+            }
+            for list in [&mut popped, &mut shifted] {
+                assert_eq!(
+                    list.pop().unwrap_err(),
+                    ListOperationErr::OperationOnEmptyList
+                );
+                assert_eq!(
+                    list.shift().unwrap_err(),
+                    ListOperationErr::OperationOnEmptyList
+                );
+                assert!(list.is_empty());
+                list.check_invariants().unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn node_iterators_run_alongside_borrowing_iterators() {
+        let list = list_of(0..3);
+        let other = list_of(10..12);
+        let mut pairs = list.cartesian_product(&other);
+        for a in list.iter() {
+            for b in other.iter() {
+                let (x, y) = pairs.next().unwrap();
+                assert!(Rc::ptr_eq(a, &x) && Rc::ptr_eq(b, &y));
+            }
+        }
+        assert!(pairs.next().is_none());
+
+        let rows = LinkedList::from(vec![list_of(0..2), list_of(2..4)]);
+        let first_row = rows.get(0).unwrap();
+        let first_row = first_row.borrow();
+        let mut cells = first_row.iter();
+        assert_eq!(*cells.next().unwrap().borrow(), 0);
+        let columns = rows.transpose().unwrap();
+        assert_eq!(*cells.next().unwrap().borrow(), 1);
+        let columns: Vec<Vec<i32>> = columns
+            .iter()
+            .map(|column| values(&column.borrow()))
+            .collect();
+        assert_eq!(columns, [[0, 2], [1, 3]]);
+    }
+
+    /// Deterministic xorshift numbers, so failures replay
+    struct Rng(u64);
+
+    impl Rng {
+        fn below(&mut self, bound: usize) -> usize {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            (self.0 % bound as u64) as usize
+        }
+    }
+
+    #[test]
+    fn lookups_stay_right_across_relinking() {
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+        let mut list = list_of(0..16);
+        let mut model: Vec<i32> = (0..16).collect();
+        let mut next = 16;
+
+        for _ in 0..400 {
+            // a lookup first, so the position cache is set when the list is relinked
+            if !model.is_empty() {
+                let index = rng.below(model.len());
+                assert_eq!(*list.get(index).unwrap().borrow(), model[index]);
+            }
+            let len = model.len();
+            match rng.below(6) {
+                0 => {
+                    let index = rng.below(len + 1);
+                    list.insert_at(Rc::new(RefCell::new(next)), index).unwrap();
+                    model.insert(index, next);
+                    next += 1;
+                }
+                1 if len > 0 => {
+                    let index = rng.below(len);
+                    assert_eq!(
+                        *list.remove_at(index).unwrap().borrow(),
+                        model.remove(index)
+                    );
+                }
+                2 if len > 0 => {
+                    let index = rng.below(len);
+                    list.move_to_front(index).unwrap();
+                    let value = model.remove(index);
+                    model.insert(0, value);
+                }
+                3 => {
+                    let index = rng.below(len + 1);
+                    let mut tail = list.split_off(index).unwrap();
+                    let moved = model.split_off(index);
+                    assert_eq!(values(&tail), moved);
+                    tail.check_invariants().unwrap();
+                    // put the tail back in front, so the order keeps changing
+                    tail.append(&mut list);
+                    list = tail;
+                    model = moved.into_iter().chain(model).collect();
+                }
+                4 if len > 1 => {
+                    let (i, j) = (rng.below(len), rng.below(len));
+                    list.swap(i, j).unwrap();
+                    model.swap(i, j);
+                }
+                _ => {
+                    if len > 0 && rng.below(2) == 0 {
+                        assert_eq!(*list.pop().unwrap().borrow(), model.pop().unwrap());
+                    } else if len > 0 {
+                        assert_eq!(*list.shift().unwrap().borrow(), model.remove(0));
+                    }
+                }
+            }
+            list.check_invariants().unwrap();
+            assert_eq!(list.size(), model.len());
+            for (index, value) in model.iter().enumerate() {
+                assert_eq!(*list.get(index).unwrap().borrow(), *value);
+            }
+        }
+    }
 }
@@ -1,13 +1,38 @@
-use super::linked_list::{List, ListOperationErr, UNEXPECTED_ERR};
-use std::{cell::RefCell, ptr, rc::Rc};
+use super::deque::Deque;
+use super::frozen_list::FrozenList;
+use super::linked_list::{
+    fmt_elements, resolve_range, resolve_signed, unwrap_or_clone, CartesianProduct, EqStrategy,
+    InvariantViolation, List, ListOperationErr, SnapshotIterator, ValueKey,
+};
+use super::statistics::{self, Numeric};
+use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    convert::Infallible,
+    hash::Hash,
+    iter::{FromIterator, Rev},
+    mem,
+    ops::{ControlFlow, Index, RangeBounds},
+    ptr::NonNull,
+    rc::{Rc, Weak},
+    sync::atomic::{self, AtomicU64},
+};
+
+/// A (possibly missing) link to a neighbouring node
+type Link2<T> = Option<Rc<RefCell<ListNode2<T>>>>;
+
+/// A (possibly missing) link back to the previous node, which does not keep it alive
+type BackLink2<T> = Option<Weak<RefCell<ListNode2<T>>>>;
 
 #[derive(Debug, Clone)]
 struct ListNode2<T> {
     content: Rc<RefCell<T>>,
-    linked_nodes: (
-        Option<Rc<RefCell<ListNode2<T>>>>,
-        Option<Rc<RefCell<ListNode2<T>>>>,
-    ),
+    /// the previous and the next node; only forward links are strong, so neighbours
+    /// never keep each other alive
+    linked_nodes: (BackLink2<T>, Link2<T>),
+    /// bumped when the node is removed from its list, invalidating its `NodeHandle`s
+    generation: u64,
 }
 
 impl<T: std::fmt::Debug> ListNode2<T> {
@@ -18,420 +43,3200 @@ impl<T: std::fmt::Debug> ListNode2<T> {
         Rc::new(RefCell::new(ListNode2 {
             content,
             linked_nodes: (None, None),
+            generation: 0,
         }))
     }
 
-    /// Breaks the link between this node and the node linked through `self.linked_nodes.0`
-    /// ### Returns
-    /// a reference to the linked node (if any)
-    fn break_link0(&mut self) -> Option<Rc<RefCell<ListNode2<T>>>> {
-        let n0 = self.linked_nodes.0.take();
-        n0.clone()?.borrow_mut().linked_nodes.1.take();
-        n0
+    /// Reads a node without tracking the borrow
+    fn peek(node: &RefCell<ListNode2<T>>) -> &ListNode2<T> {
+        // SAFETY: nodes are only mutably borrowed by methods of the list linking them, which
+        // take it by `&mut self` (handles to nodes of other lists are rejected), so no `RefMut`
+        // to a node can exist while that list (and thus `node`) is borrowed shared.
+        unsafe { &*node.as_ptr() }
     }
 
-    /// Breaks the link between this node and the node linked through `self.linked_nodes.1`
-    /// ### Returns
-    /// a reference to the linked node (if any)
-    fn break_link1(&mut self) -> Option<Rc<RefCell<ListNode2<T>>>> {
-        let n1 = self.linked_nodes.1.take();
-        n1.clone()?.borrow_mut().linked_nodes.0.take();
-        n1
+    /// Marks the node as removed from its list
+    fn retire(&mut self) {
+        self.generation += 1;
+    }
+}
+
+impl<T> ListNode2<T> {
+    /// #### Returns
+    /// a reference to the previous node
+    fn prev_node(&self) -> Link2<T> {
+        self.linked_nodes.0.as_ref()?.upgrade()
+    }
+
+    /// Reads the previous node without upgrading the back link
+    fn prev_ref(&self) -> Option<&RefCell<ListNode2<T>>> {
+        // SAFETY: a back link is only set while the node is linked after the node it points
+        // to, which the forward link to this node keeps alive; relinking either of them takes
+        // the list linking them by `&mut self` (handles to nodes of other lists are rejected),
+        // so it cannot happen while `self` is borrowed from that list.
+        self.linked_nodes
+            .0
+            .as_ref()
+            .map(|prev| unsafe { &*prev.as_ptr() })
+    }
+}
+
+/// ### Summary
+/// A handle to a single node of a `LinkedList2`, giving O(1) access to it
+/// for as long as the node is linked in its list.
+///
+/// A handle does not keep its node alive. Every node carries a generation
+/// that is bumped when it is removed, so using a handle to a removed node
+/// fails with `ListOperationErr::StaleHandle`.
+///
+/// A handle also remembers the list it was taken from: passing it to another list fails with
+/// `ListOperationErr::ForeignHandle`, and so does using it after its node was moved to another
+/// list by `append`, `chunks` or a cursor splice. `split_off` retires the nodes it moves instead,
+/// so their handles fail with `ListOperationErr::StaleHandle`.
+#[derive(Debug)]
+pub struct NodeHandle<T> {
+    node: Weak<RefCell<ListNode2<T>>>,
+    generation: u64,
+    /// the id of the list the node was linked in when the handle was created
+    list: u64,
+}
+
+impl<T> Clone for NodeHandle<T> {
+    fn clone(&self) -> Self {
+        NodeHandle {
+            node: self.node.clone(),
+            generation: self.generation,
+            list: self.list,
+        }
+    }
+}
+
+impl<T> NodeHandle<T> {
+    fn new(node: &Rc<RefCell<ListNode2<T>>>, list: u64) -> Self {
+        NodeHandle {
+            node: Rc::downgrade(node),
+            generation: node.borrow().generation,
+            list,
+        }
+    }
+
+    /// Get the node the handle points to, as long as it was not removed since the handle was created
+    fn node(&self) -> Result<Rc<RefCell<ListNode2<T>>>, ListOperationErr> {
+        let node = self.node.upgrade().ok_or(ListOperationErr::StaleHandle)?;
+        if node.borrow().generation != self.generation {
+            return Err(ListOperationErr::StaleHandle);
+        }
+        Ok(node)
+    }
+
+    /// checks whether the node of the handle is still in its list
+    pub fn is_valid(&self) -> bool {
+        self.node().is_ok()
+    }
+
+    /// #### Returns
+    /// a reference to the item held by the node
+    pub fn content(&self) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        Ok(self.node()?.borrow().content.clone())
+    }
+
+    /// #### Returns
+    /// a handle to the node after this one (if any)
+    pub fn next(&self) -> Option<NodeHandle<T>> {
+        let node = self.node().ok()?;
+        let next = node.borrow().linked_nodes.1.clone()?;
+        Some(NodeHandle::new(&next, self.list))
+    }
+
+    /// #### Returns
+    /// a handle to the node before this one (if any)
+    pub fn prev(&self) -> Option<NodeHandle<T>> {
+        let node = self.node().ok()?;
+        let prev = node.borrow().prev_node()?;
+        Some(NodeHandle::new(&prev, self.list))
+    }
+
+    /// #### Returns
+    /// a key identifying the node, unique among the nodes currently alive
+    pub fn id(&self) -> usize {
+        self.node.as_ptr() as *const () as usize
     }
 }
 
+/// The index of a node passed by an earlier lookup, with the node itself
+type Position<T> = Option<(usize, NonNull<RefCell<ListNode2<T>>>)>;
+
 pub struct LinkedList2<T: std::fmt::Debug> {
     head: Option<Rc<RefCell<ListNode2<T>>>>,
     tail: Option<Rc<RefCell<ListNode2<T>>>>,
     size: usize,
+    /// the index of the node before the last one looked up past the head, and that node, so
+    /// the next lookup can start from there if it is closer than either end; cleared whenever
+    /// nodes are relinked
+    position: Cell<Position<T>>,
+    /// identifies the list to its `NodeHandle`s, 0 until the first handle is created
+    id: Cell<u64>,
 }
 
+/// The id of the next list to create a `NodeHandle`, ids are never reused
+static NEXT_LIST_ID: AtomicU64 = AtomicU64::new(1);
+
 impl<T: std::fmt::Debug> LinkedList2<T> {
-    /// Constructs an empty `LinkedList2<T>`
-    pub fn new() -> Self {
+    /// Constructs an empty `LinkedList2<T>`.
+    ///
+    /// An empty list does not allocate, so this can be used in const contexts
+    /// (e.g. a `const` item or a `thread_local!` initializer).
+    pub const fn new() -> Self {
         LinkedList2 {
             head: None,
             tail: None,
             size: 0,
+            position: Cell::new(None),
+            id: Cell::new(0),
+        }
+    }
+
+    /// #### Returns
+    /// a reference to the first element, without walking the list
+    pub fn front(&self) -> Option<Rc<RefCell<T>>> {
+        Some(self.head.as_ref()?.borrow().content.clone())
+    }
+
+    /// #### Returns
+    /// a reference to the last element, without walking the list
+    pub fn back(&self) -> Option<Rc<RefCell<T>>> {
+        Some(self.tail.as_ref()?.borrow().content.clone())
+    }
+
+    /// Calls `f` with the first element borrowed mutably
+    /// #### Returns
+    /// the result of `f`, `None` on an empty list
+    pub fn front_mut<R, F: FnOnce(&mut T) -> R>(&mut self, f: F) -> Option<R> {
+        let head = self.head.as_ref()?.borrow();
+        let mut content = head.content.borrow_mut();
+        Some(f(&mut content))
+    }
+
+    /// Calls `f` with the last element borrowed mutably
+    /// #### Returns
+    /// the result of `f`, `None` on an empty list
+    pub fn back_mut<R, F: FnOnce(&mut T) -> R>(&mut self, f: F) -> Option<R> {
+        let tail = self.tail.as_ref()?.borrow();
+        let mut content = tail.content.borrow_mut();
+        Some(f(&mut content))
+    }
+
+    /// add an item to the front of the list in O(1)
+    /// #### Params
+    /// - `item` - a reference to the item to add
+    pub fn push_front(&mut self, item: Rc<RefCell<T>>) {
+        trace_op!(list = "LinkedList2", op = "push_front", size = self.size);
+        let head = self.head.clone();
+        self.link_between(ListNode2::new(item), None, head);
+    }
+
+    /// add an item to the end of the list in O(1), same as `add`
+    /// #### Params
+    /// - `item` - a reference to the item to add
+    pub fn push_back(&mut self, item: Rc<RefCell<T>>) {
+        self.add(item);
+    }
+
+    /// Walks the nodes and checks that the links agree with `head`, `tail` and `size`,
+    /// and that every node links back to the node before it.
+    ///
+    /// Meant for tests and debugging: it is O(n) and keeps a set of the visited nodes.
+    /// #### Returns
+    /// the first broken invariant found
+    pub fn check_invariants(&self) -> Result<(), InvariantViolation> {
+        let tail = match (&self.head, &self.tail) {
+            (Some(_), Some(tail)) => tail,
+            (None, None) if self.size == 0 => return Ok(()),
+            (None, None) => {
+                return Err(InvariantViolation::SizeMismatch {
+                    size: self.size,
+                    counted: 0,
+                })
+            }
+            _ => return Err(InvariantViolation::DanglingEnd),
+        };
+
+        let mut visited = HashSet::new();
+        let mut last: Option<&RefCell<ListNode2<T>>> = None;
+        let mut cur = self.head.as_deref();
+        while let Some(node) = cur {
+            let index = visited.len();
+            if !visited.insert(node as *const RefCell<ListNode2<T>>) {
+                return Err(InvariantViolation::Cycle { index });
+            }
+            // upgrading rather than reading through the back link, which may dangle here
+            let prev = ListNode2::peek(node).prev_node();
+            let links_back = match (&prev, last) {
+                (Some(prev), Some(last)) => std::ptr::eq(Rc::as_ptr(prev), last),
+                (None, None) => true,
+                _ => false,
+            };
+            if !links_back {
+                return Err(InvariantViolation::BrokenBackLink { index });
+            }
+            last = Some(node);
+            cur = ListNode2::peek(node).linked_nodes.1.as_deref();
+        }
+
+        if ListNode2::peek(tail).linked_nodes.1.is_some() {
+            return Err(InvariantViolation::TailHasNext);
+        }
+        if !last.is_some_and(|last| std::ptr::eq(last, &**tail)) {
+            return Err(InvariantViolation::TailUnreachable);
         }
+        if visited.len() != self.size {
+            return Err(InvariantViolation::SizeMismatch {
+                size: self.size,
+                counted: visited.len(),
+            });
+        }
+        Ok(())
     }
 
     /// Check index bounds
     pub fn index_check(&self, index: usize) -> Result<(), ListOperationErr> {
         if self.size <= index {
-            Err(ListOperationErr::IndexOutOfBounds)
+            Err(ListOperationErr::IndexOutOfBounds {
+                index,
+                len: self.size,
+            })
         } else {
             Ok(())
         }
     }
 
+    /// get a reference to the item at the specified index, counting back from the end
+    /// for negative indices (`-1` is the last element)
+    /// #### Params
+    /// - `index` - the index to lookup
+    pub fn get_signed(&self, index: isize) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        self.get(resolve_signed(index, self.size)?)
+    }
+
+    /// insert an item at a specific index, counting back from the end for negative indices,
+    /// so `-1` inserts before the last element
+    /// #### Params
+    /// - `item` - a reference to the item to insert
+    /// - `index` - the index to insert the item at, `size` appends it
+    pub fn insert_at_signed(
+        &mut self,
+        item: Rc<RefCell<T>>,
+        index: isize,
+    ) -> Result<(), ListOperationErr> {
+        let index = resolve_signed(index, self.size)?;
+        self.insert_at(item, index)
+    }
+
+    /// removes the item at the specified index, counting back from the end for negative
+    /// indices (`-1` is the last element)
+    /// #### Params
+    /// - `index` - the index of the item to remove
+    pub fn remove_at_signed(&mut self, index: isize) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        let index = resolve_signed(index, self.size)?;
+        self.remove_at(index)
+    }
+
     /// Removes the first element of the list
     pub fn shift(&mut self) -> Result<Rc<RefCell<T>>, ListOperationErr> {
-        // if head
-        let after_head = self
+        trace_op!(list = "LinkedList2", op = "shift", size = self.size);
+        let head = self
             .head
             .clone()
-            .ok_or(ListOperationErr::OperationOnEmptyList)?
-            .borrow()
-            .linked_nodes
-            .1
-            .clone();
-        match after_head {
-            Some(n) => {
-                // set node after head node as head
-                self.size -= 1;
-                let tmp = Some(
-                    self.head
-                        .clone()
-                        .ok_or(UNEXPECTED_ERR)?
-                        .borrow()
-                        .content
-                        .clone(),
-                );
-                self.head.replace(n.clone());
-                n.borrow_mut().break_link0();
-                tmp.ok_or(UNEXPECTED_ERR)
-            }
-            None => {
-                // if list size = 1
-                // reset
-                self.size -= 1;
-                self.head.take();
-                Ok(self
-                    .tail
-                    .take()
-                    .ok_or(UNEXPECTED_ERR)?
-                    .borrow()
-                    .content
-                    .clone())
-            }
-        }
+            .ok_or(ListOperationErr::OperationOnEmptyList)?;
+        Ok(self.remove_node(&head))
     }
 
     /// Removes the last element of the list
     pub fn pop(&mut self) -> Result<Rc<RefCell<T>>, ListOperationErr> {
-        // if tail
-        let tail_prev = self
+        trace_op!(list = "LinkedList2", op = "pop", size = self.size);
+        let tail = self
             .tail
             .clone()
-            .ok_or(ListOperationErr::OperationOnEmptyList)?
-            .borrow()
-            .linked_nodes
-            .0
-            .clone();
-
-        match tail_prev {
-            Some(n) => {
-                // set node before tail node as tail
-                self.size -= 1;
-                let tmp = Some(
-                    self.tail
-                        .clone()
-                        .ok_or(UNEXPECTED_ERR)?
-                        .borrow()
-                        .content
-                        .clone(),
-                );
-                self.tail.replace(n.clone());
+            .ok_or(ListOperationErr::OperationOnEmptyList)?;
+        Ok(self.remove_node(&tail))
+    }
 
-                n.borrow_mut().break_link1();
-                tmp.ok_or(UNEXPECTED_ERR)
-            }
-            None => {
-                // if list size = 1
-                // reset
-                self.size -= 1;
-                self.head.take();
-                Ok(self
-                    .tail
-                    .take()
-                    .ok_or(UNEXPECTED_ERR)?
-                    .borrow()
-                    .content
-                    .clone())
+    /// Removes the element at `index` and takes its value out of the `Rc<RefCell<T>>`
+    /// #### Params
+    /// - `index` - the index of the element to remove
+    /// #### Returns
+    /// the owned value, or `SharedElement` if it is still referenced outside the list, in
+    /// which case the element stays in place
+    pub fn remove_at_value(&mut self, index: usize) -> Result<T, ListOperationErr> {
+        if Rc::strong_count(&ListNode2::peek(self.node_ref_at(index)?).content) > 1 {
+            return Err(ListOperationErr::SharedElement { index });
+        }
+        match Rc::try_unwrap(self.remove_at(index)?) {
+            Ok(content) => Ok(content.into_inner()),
+            Err(content) => {
+                // a node kept alive outside the list still holds the element, put it back
+                self.insert_at(content, index)?;
+                Err(ListOperationErr::SharedElement { index })
             }
         }
     }
 
-    /// Get list node at `index`
-    fn get_node_at(&self, index: usize) -> Result<Rc<RefCell<ListNode2<T>>>, ListOperationErr> {
-        self.index_check(index)?;
-
-        let mut cur = self.head.clone();
-        for _ in 0..index {
-            cur.replace(
-                cur.clone()
-                    .ok_or(UNEXPECTED_ERR)?
-                    .borrow()
-                    .linked_nodes
-                    .1
-                    .clone()
-                    .ok_or(UNEXPECTED_ERR)?,
-            );
+    /// Removes the first element of the list and takes its value, see `remove_at_value`
+    pub fn shift_value(&mut self) -> Result<T, ListOperationErr> {
+        if self.is_empty() {
+            return Err(ListOperationErr::OperationOnEmptyList);
         }
-        cur.ok_or(UNEXPECTED_ERR)
+        self.remove_at_value(0)
     }
 
-    /// Links `node0` with `node1` through `node0`'s link 1 and `node1`'s link 0
-    fn link_nodes(
-        node0: Rc<RefCell<ListNode2<T>>>,
-        node1: Rc<RefCell<ListNode2<T>>>,
-    ) -> (
-        Option<Rc<RefCell<ListNode2<T>>>>,
-        Option<Rc<RefCell<ListNode2<T>>>>,
-    ) {
-        let node0_old_link = node0.borrow_mut().break_link1();
-        let node1_old_link = node1.borrow_mut().break_link0();
+    /// Removes the last element of the list and takes its value, see `remove_at_value`
+    pub fn pop_value(&mut self) -> Result<T, ListOperationErr> {
+        let last = self
+            .size
+            .checked_sub(1)
+            .ok_or(ListOperationErr::OperationOnEmptyList)?;
+        self.remove_at_value(last)
+    }
 
-        node0.borrow_mut().linked_nodes.1.replace(node1.clone());
-        node1.borrow_mut().linked_nodes.0.replace(node0.clone());
+    /// Splits the list into its first element and the rest of the list
+    /// #### Returns
+    /// `(first_element, rest_of_list)`
+    pub fn split_first(mut self) -> Result<(Rc<RefCell<T>>, Self), ListOperationErr> {
+        let first = self.shift()?;
+        Ok((first, self))
+    }
 
-        (node0_old_link, node1_old_link)
+    /// Splits the list into its last element and the rest of the list
+    /// #### Returns
+    /// `(rest_of_list, last_element)`
+    pub fn split_last(mut self) -> Result<(Self, Rc<RefCell<T>>), ListOperationErr> {
+        let last = self.pop()?;
+        Ok((self, last))
     }
-}
 
-#[derive(Debug)]
-pub struct LinkedList2Iterator<T> {
-    current: Option<Rc<RefCell<ListNode2<T>>>>,
-}
+    /// Moves every element of `other` to the end of this list in O(1), leaving `other` empty.
+    ///
+    /// Handles to nodes of `other` are invalidated.
+    /// #### Params
+    /// - `other` - the list to move elements from
+    pub fn append(&mut self, other: &mut Self) {
+        let tail = self.tail.clone();
+        self.splice_between(other, tail, None);
+    }
 
-impl<T: std::fmt::Debug> Clone for LinkedList2Iterator<T> {
-    fn clone(&self) -> Self {
-        Self {
-            current: self.current.clone(),
-        }
+    /// Consumes both lists
+    /// #### Returns
+    /// a list with the elements of this list followed by those of `other`
+    pub fn concat(mut self, mut other: Self) -> Self {
+        self.append(&mut other);
+        self
     }
-}
 
-impl<T: std::fmt::Debug> Iterator for LinkedList2Iterator<T> {
-    type Item = Rc<RefCell<T>>;
+    /// Detaches the elements from `index` onward into a new list, relinking nodes in place.
+    ///
+    /// Handles to the elements before `index` stay valid, handles to the moved elements are
+    /// invalidated unless every element moves (`index` 0).
+    /// #### Params
+    /// - `index` - the index of the first element to move, at most the size of the list
+    /// #### Returns
+    /// a list holding the elements from `index` onward
+    pub fn split_off(&mut self, index: usize) -> Result<Self, ListOperationErr> {
+        if index > self.size {
+            return Err(ListOperationErr::IndexOutOfBounds {
+                index,
+                len: self.size,
+            });
+        }
+        if index == 0 {
+            return Ok(mem::take(self));
+        }
+        if index == self.size {
+            return Ok(LinkedList2::new());
+        }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let c = self.current.clone()?;
-        let result = Some(c.clone().borrow_mut().content.clone());
+        let rest = self.detach_from(index)?;
+        touch_nodes!(rest.size);
+        let mut cur = rest.head.clone();
+        while let Some(node) = cur {
+            let mut node = node.borrow_mut();
+            node.retire();
+            cur = node.linked_nodes.1.clone();
+        }
+        Ok(rest)
+    }
 
-        match c.borrow().linked_nodes.1.clone() {
-            Some(nxt) => {
-                // set `current.linked_node` as current
-                self.current.replace(nxt);
-            }
-            None => {
-                // set `current` to `None`
-                self.current.take();
+    /// Relinks the nodes from `index` onward into a new list, leaving their handles to the caller
+    /// #### Params
+    /// - `index` - the index of the first node to move, past the head and at most the size of the list
+    fn detach_from(&mut self, index: usize) -> Result<Self, ListOperationErr> {
+        let last_kept = self.node_ref_at(index - 1)?.clone();
+        self.forget_position();
+        let head = last_kept.borrow_mut().linked_nodes.1.take();
+        let tail = match &head {
+            Some(head) => {
+                head.borrow_mut().linked_nodes.0 = None;
+                self.tail.replace(last_kept)
             }
+            None => None,
         };
-
-        result
+        let size = self.size - index;
+        self.size = index;
+        Ok(LinkedList2 {
+            head,
+            tail,
+            size,
+            position: Cell::new(None),
+            id: Cell::new(0),
+        })
     }
-}
-
-impl<T: std::fmt::Debug> IntoIterator for LinkedList2<T> {
-    type Item = Rc<RefCell<T>>;
 
-    type IntoIter = LinkedList2Iterator<T>;
+    /// Splits the list in two at `index`, relinking nodes in place
+    /// #### Params
+    /// - `index` - the index of the first element of the second list, at most the size of the list
+    /// #### Returns
+    /// `(elements_before_index, elements_from_index)`
+    pub fn split_at(mut self, index: usize) -> Result<(Self, Self), ListOperationErr> {
+        let rest = self.split_off(index)?;
+        Ok((self, rest))
+    }
 
-    fn into_iter(self) -> Self::IntoIter {
-        LinkedList2Iterator {
-            current: self.head.clone(),
-        }
+    /// Splits the list into consecutive lists of `size` elements, the last one possibly shorter.
+    ///
+    /// Each chunk is detached in place, so nodes are relinked rather than copied, and handles to
+    /// nodes of the list are invalidated. Panics if `size` is 0.
+    /// #### Params
+    /// - `size` - the number of elements per chunk
+    pub fn chunks(mut self, size: usize) -> LinkedList2Chunks<T> {
+        assert!(size > 0, "chunk size must be non-zero");
+        self.forget_handles();
+        LinkedList2Chunks { list: self, size }
     }
-}
 
-impl<T: std::fmt::Debug> Clone for LinkedList2<T> {
-    fn clone(&self) -> Self {
-        let mut clone = LinkedList2::new();
-        let mut cur = self.head.clone();
-        loop {
-            match cur {
-                Some(c) => {
-                    clone.add(c.clone().borrow().content.clone());
-                    cur = c.borrow().linked_nodes.1.clone();
+    /// Builds a list from already allocated contents in one pass,
+    /// setting `head`, `tail` and `size` once at the end
+    pub(crate) fn from_contents(contents: Vec<Rc<RefCell<T>>>) -> Self {
+        let size = contents.len();
+        let mut head = None;
+        let mut tail: Link2<T> = None;
+
+        for content in contents {
+            let node = ListNode2::new(content);
+            match tail.take() {
+                Some(prev) => {
+                    node.borrow_mut().linked_nodes.0 = Some(Rc::downgrade(&prev));
+                    prev.borrow_mut().linked_nodes.1 = Some(node.clone());
                 }
-                None => break,
+                None => head = Some(node.clone()),
             }
+            tail = Some(node);
+        }
+
+        LinkedList2 {
+            head,
+            tail,
+            size,
+            position: Cell::new(None),
+            id: Cell::new(0),
         }
-        clone
     }
-}
 
-impl<T: std::fmt::Debug> List<T> for LinkedList2<T> {
-    fn add(&mut self, item: Rc<RefCell<T>>) {
-        // init node for new item
-        let node = ListNode2::new(item.clone());
+    /// Borrows the element at `index` for as long as the guard lives
+    /// #### Params
+    /// - `index` - the index to lookup
+    /// #### Returns
+    /// a read guard to the element, failing with `BorrowConflict` if it is mutably borrowed elsewhere
+    pub fn borrow_at(&self, index: usize) -> Result<Ref<'_, T>, ListOperationErr> {
+        ListNode2::peek(self.node_ref_at(index)?)
+            .content
+            .try_borrow()
+            .map_err(|_| ListOperationErr::BorrowConflict { index })
+    }
 
-        match self.tail {
-            Some(ref mut tail) => {
-                // on non-empty list
-                Self::link_nodes(tail.clone(), node.clone());
-                tail.clone_from(&node);
-            }
-            None => {
-                // On empty, use the same node for head and tail
-                self.tail = Some(node);
-                self.head = self.tail.clone();
-            }
-        }
+    /// Mutably borrows the element at `index` for as long as the guard lives
+    /// #### Params
+    /// - `index` - the index to lookup
+    /// #### Returns
+    /// a write guard to the element, failing with `BorrowConflict` if it is borrowed elsewhere
+    pub fn borrow_mut_at(&mut self, index: usize) -> Result<RefMut<'_, T>, ListOperationErr> {
+        ListNode2::peek(self.node_ref_at(index)?)
+            .content
+            .try_borrow_mut()
+            .map_err(|_| ListOperationErr::BorrowConflict { index })
+    }
 
-        // increment size
-        self.size += 1;
+    /// Replaces the element at `index` in place, keeping its `Rc` (and anyone sharing it)
+    /// #### Params
+    /// - `index` - the index of the element to replace
+    /// - `value` - the new value
+    /// #### Returns
+    /// the previous value, failing with `BorrowConflict` if the element is borrowed elsewhere
+    pub fn set(&mut self, index: usize, value: T) -> Result<T, ListOperationErr> {
+        trace_op!(list = "LinkedList2", op = "set", index, size = self.size);
+        let mut content = self.borrow_mut_at(index)?;
+        Ok(mem::replace(&mut *content, value))
     }
 
-    fn add_raw(&mut self, item: T) {
-        self.add(Rc::new(RefCell::new(item)));
+    /// Calls `f` with the element at `index` borrowed
+    /// #### Params
+    /// - `index` - the index to lookup
+    /// - `f` - called with the element
+    /// #### Returns
+    /// the result of `f`, failing with `BorrowConflict` if the element is mutably borrowed elsewhere
+    pub fn with<R, F: FnOnce(&T) -> R>(&self, index: usize, f: F) -> Result<R, ListOperationErr> {
+        Ok(f(&*self.borrow_at(index)?))
     }
 
-    fn insert_at(&mut self, item: Rc<RefCell<T>>, index: usize) -> Result<(), ListOperationErr> {
+    /// Calls `f` with the element at `index` borrowed mutably
+    /// #### Params
+    /// - `index` - the index to lookup
+    /// - `f` - called with the element
+    /// #### Returns
+    /// the result of `f`, failing with `BorrowConflict` if the element is borrowed elsewhere
+    pub fn update<R, F: FnOnce(&mut T) -> R>(
+        &mut self,
+        index: usize,
+        f: F,
+    ) -> Result<R, ListOperationErr> {
+        Ok(f(&mut *self.borrow_mut_at(index)?))
+    }
+
+    /// Exchanges the elements at `i` and `j` by swapping the contents of their nodes
+    ///
+    /// Node handles stay with their positions, so they see the swapped elements afterwards.
+    /// #### Params
+    /// - `i` - the index of the first element
+    /// - `j` - the index of the second element
+    pub fn swap(&mut self, i: usize, j: usize) -> Result<(), ListOperationErr> {
+        trace_op!(list = "LinkedList2", op = "swap", i, j, size = self.size);
+        let first = self.get_node_at(i)?;
+        let second = self.get_node_at(j)?;
+        if i != j {
+            mem::swap(
+                &mut first.borrow_mut().content,
+                &mut second.borrow_mut().content,
+            );
+        }
+        Ok(())
+    }
+
+    /// Get list node at `index`
+    fn get_node_at(&self, index: usize) -> Result<Rc<RefCell<ListNode2<T>>>, ListOperationErr> {
+        self.node_ref_at(index).cloned()
+    }
+
+    /// Get a reference to the list node at `index`, borrowed from the list itself
+    fn node_ref_at(&self, index: usize) -> Result<&Rc<RefCell<ListNode2<T>>>, ListOperationErr> {
         self.index_check(index)?;
 
         if index == 0 {
-            // if head
-            self.head.replace(Rc::new(RefCell::new(ListNode2 {
-                content: item,
-                linked_nodes: (None, self.head.clone()),
-            })));
-            // increment size
-            self.size += 1;
-        } else if index == self.size - 1 {
-            // if tail
-            self.add(item);
+            touch_nodes!(1);
+            return self
+                .head
+                .as_ref()
+                .ok_or(ListOperationErr::IndexOutOfBounds {
+                    index,
+                    len: self.size,
+                });
+        }
+
+        // the link to the node is held by the node before it: walk there from whichever is
+        // closest, either end or the node passed by the last lookup
+        let target = index - 1;
+        let (mut cur, mut at) = if target <= self.size - 1 - target {
+            (self.head.as_deref(), 0)
         } else {
-            let orig = self.get_node_at(index)?;
-            let prev = orig.borrow_mut().break_link0();
-            Self::link_nodes(
-                prev.ok_or(UNEXPECTED_ERR)?,
-                Rc::new(RefCell::new(ListNode2 {
-                    content: item,
-                    linked_nodes: (None, Some(orig)),
-                })),
-            );
-            // increment size
-            self.size += 1;
+            (self.tail.as_deref(), self.size - 1)
+        };
+        if let Some((cached, node)) = self.position.get() {
+            if cached.abs_diff(target) < at.abs_diff(target) {
+                // SAFETY: the position is cleared whenever this list relinks nodes, and only this
+                // list relinks them (handles to nodes of other lists are rejected), so the node is
+                // still linked into this list, which keeps it alive for the borrow of `self`
+                cur = Some(unsafe { node.as_ref() });
+                at = cached;
+            }
         }
 
-        Ok(())
+        let steps = at.abs_diff(target);
+        let forward = at < target;
+        touch_nodes!(steps + 1);
+        for _ in 0..steps {
+            cur = cur.and_then(|c| {
+                let node = ListNode2::peek(c);
+                if forward {
+                    node.linked_nodes.1.as_deref()
+                } else {
+                    node.prev_ref()
+                }
+            });
+        }
+        // the walk only comes up short if `index` is past the last node
+        let found =
+            cur.and_then(|prev| Some((prev, ListNode2::peek(prev).linked_nodes.1.as_ref()?)));
+        let (prev, link) = found.ok_or(ListOperationErr::IndexOutOfBounds {
+            index,
+            len: self.size,
+        })?;
+        self.position.set(Some((target, NonNull::from(prev))));
+        Ok(link)
     }
 
-    fn insert_raw_at(&mut self, item: T, index: usize) -> Result<(), ListOperationErr> {
-        self.insert_at(Rc::new(RefCell::new(item)), index)
+    /// Forgets the last node looked up, whose index or link relinking may have changed
+    fn forget_position(&mut self) {
+        *self.position.get_mut() = None;
     }
 
-    fn get(&self, index: usize) -> Result<Rc<RefCell<T>>, ListOperationErr> {
-        self.index_check(index)?;
+    /// #### Returns
+    /// the id handles to nodes of this list carry, assigned on first use
+    fn id(&self) -> u64 {
+        if self.id.get() == 0 {
+            self.id
+                .set(NEXT_LIST_ID.fetch_add(1, atomic::Ordering::Relaxed));
+        }
+        self.id.get()
+    }
 
-        let mut iter = self.clone().into_iter();
+    /// Invalidates every handle to a node of this list, once nodes were moved to another list
+    fn forget_handles(&mut self) {
+        *self.id.get_mut() = 0;
+    }
 
-        for _ in 0..index {
-            iter.next();
+    /// Get the node of `handle`, as long as it is still linked in this list
+    fn owned_node(
+        &self,
+        handle: &NodeHandle<T>,
+    ) -> Result<Rc<RefCell<ListNode2<T>>>, ListOperationErr> {
+        if handle.list != self.id.get() {
+            return Err(ListOperationErr::ForeignHandle);
         }
+        handle.node()
+    }
 
-        iter.next().clone().ok_or(UNEXPECTED_ERR)
+    /// Links a detached `node` between the adjacent nodes `prev` and `next`,
+    /// where a missing neighbour means the node becomes the head (or tail)
+    fn link_between(&mut self, node: Rc<RefCell<ListNode2<T>>>, prev: Link2<T>, next: Link2<T>) {
+        self.forget_position();
+        touch_nodes!(1 + prev.is_some() as u8 + next.is_some() as u8);
+        match &prev {
+            Some(prev) => prev.borrow_mut().linked_nodes.1 = Some(node.clone()),
+            None => self.head = Some(node.clone()),
+        }
+        match &next {
+            Some(next) => next.borrow_mut().linked_nodes.0 = Some(Rc::downgrade(&node)),
+            None => self.tail = Some(node.clone()),
+        }
+        node.borrow_mut().linked_nodes = (prev.as_ref().map(Rc::downgrade), next);
+        self.size += 1;
     }
 
-    fn contains(&self, item: Rc<RefCell<T>>) -> bool {
-        let clone = self.clone();
-        let mut result = false;
+    /// Unlinks `node` from the list, joining its neighbours and keeping `head`, `tail` and `size` in sync
+    fn unlink(&mut self, node: &Rc<RefCell<ListNode2<T>>>) {
+        self.forget_position();
+        let (prev, next) = {
+            let mut node = node.borrow_mut();
+            let prev = node.prev_node();
+            node.linked_nodes.0 = None;
+            (prev, node.linked_nodes.1.take())
+        };
+        touch_nodes!(1 + prev.is_some() as u8 + next.is_some() as u8);
+        match &next {
+            Some(next) => next.borrow_mut().linked_nodes.0 = prev.as_ref().map(Rc::downgrade),
+            None => self.tail = prev.clone(),
+        }
+        match prev {
+            Some(prev) => prev.borrow_mut().linked_nodes.1 = next,
+            None => self.head = next,
+        }
+        self.size -= 1;
+    }
+
+    /// Unlinks `node` for good, invalidating its handles
+    /// #### Returns
+    /// a reference to the item held by `node`
+    fn remove_node(&mut self, node: &Rc<RefCell<ListNode2<T>>>) -> Rc<RefCell<T>> {
+        self.unlink(node);
+        let mut node = node.borrow_mut();
+        node.retire();
+        node.content.clone()
+    }
+
+    /// Walks the list by reference, calling `f` on every element until it breaks.
+    ///
+    /// No `Rc` is cloned along the way, making this the cheapest way to read the whole list.
+    /// #### Params
+    /// - `f` - called with each element, in order
+    /// #### Returns
+    /// `ControlFlow::Break` if `f` stopped the walk early
+    pub fn visit<F: FnMut(&T) -> ControlFlow<()>>(&self, mut f: F) -> ControlFlow<()> {
+        match self.try_visit(|item| Ok::<_, Infallible>(f(item))) {
+            Ok(ControlFlow::Break(_)) => ControlFlow::Break(()),
+            _ => ControlFlow::Continue(()),
+        }
+    }
 
-        for i in clone {
-            if ptr::eq(item.as_ref(), i.as_ref()) {
-                result = true;
+    /// Walks the list by reference like `visit`, but `f` can also break with a value
+    /// or fail, which stops the walk and hands the error back.
+    /// #### Params
+    /// - `f` - called with each element, in order
+    /// #### Returns
+    /// - `Ok(ControlFlow::Continue(()))` if every element was visited
+    /// - `Ok(ControlFlow::Break((index, value)))` if `f` broke at `index`
+    /// - `Err((index, err))` if `f` failed at `index`
+    pub fn try_visit<B, E, F: FnMut(&T) -> Result<ControlFlow<B>, E>>(
+        &self,
+        mut f: F,
+    ) -> Result<ControlFlow<(usize, B)>, (usize, E)> {
+        let mut cur = self.head.as_deref();
+        let mut index = 0;
+        while let Some(c) = cur {
+            let node = ListNode2::peek(c);
+            match f(&node.content.borrow()) {
+                Ok(ControlFlow::Continue(())) => {}
+                Ok(ControlFlow::Break(value)) => return Ok(ControlFlow::Break((index, value))),
+                Err(err) => return Err((index, err)),
             }
+            cur = node.linked_nodes.1.as_deref();
+            index += 1;
         }
+        Ok(ControlFlow::Continue(()))
+    }
 
-        result
+    /// #### Params
+    /// - `f` - called with each element, in order
+    /// #### Returns
+    /// a new list holding the results of `f`
+    pub fn map<U: std::fmt::Debug, F: FnMut(&T) -> U>(&self, mut f: F) -> LinkedList2<U> {
+        self.iter().map(|content| f(&content.borrow())).collect()
     }
 
-    fn remove(&mut self, item: Rc<RefCell<T>>) -> Result<(), ListOperationErr> {
-        let mut cur = self.head.clone();
+    /// Copies the list together with its elements, unlike `clone` which shares them
+    /// #### Returns
+    /// a new list holding clones of the element values, each in a fresh cell
+    pub fn deep_clone(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.map(T::clone)
+    }
 
-        // check if empty
-        if self.is_empty() {
-            Err(UNEXPECTED_ERR)
+    /// #### Params
+    /// - `keep` - called with each element, in order
+    /// #### Returns
+    /// a new list sharing the elements for which `keep` returns `true`
+    pub fn filter<F: FnMut(&T) -> bool>(&self, mut keep: F) -> Self {
+        self.iter()
+            .filter(|content| keep(&content.borrow()))
+            .cloned()
+            .collect()
+    }
+
+    /// Combines the elements into a single value, from the head
+    /// #### Params
+    /// - `init` - the starting value
+    /// - `f` - called with the value so far and each element, in order
+    /// #### Returns
+    /// the value returned by the last call to `f`, `init` for an empty list
+    pub fn fold<B, F: FnMut(B, &T) -> B>(&self, init: B, mut f: F) -> B {
+        self.iter()
+            .fold(init, |acc, content| f(acc, &content.borrow()))
+    }
+
+    /// Get the first element matching `predicate`
+    /// #### Params
+    /// - `predicate` - called with each element, in order, until it returns `true`
+    /// #### Returns
+    /// a reference to the matching element, `None` if there is none
+    pub fn find<F: FnMut(&T) -> bool>(&self, mut predicate: F) -> Option<Rc<RefCell<T>>> {
+        self.iter()
+            .find(|content| predicate(&content.borrow()))
+            .cloned()
+    }
+
+    /// Get the index of the first element matching `predicate`
+    /// #### Params
+    /// - `predicate` - called with each element, in order, until it returns `true`
+    pub fn position<F: FnMut(&T) -> bool>(&self, mut predicate: F) -> Option<usize> {
+        self.iter().position(|content| predicate(&content.borrow()))
+    }
+
+    /// #### Params
+    /// - `predicate` - called with each element, in order, until it returns `true`
+    /// #### Returns
+    /// `true` if any element matches `predicate`, `false` for an empty list
+    pub fn any<F: FnMut(&T) -> bool>(&self, mut predicate: F) -> bool {
+        self.iter().any(|content| predicate(&content.borrow()))
+    }
+
+    /// #### Params
+    /// - `predicate` - called with each element, in order, until it returns `false`
+    /// #### Returns
+    /// `true` if every element matches `predicate`, `true` for an empty list
+    pub fn all<F: FnMut(&T) -> bool>(&self, mut predicate: F) -> bool {
+        self.iter().all(|content| predicate(&content.borrow()))
+    }
+
+    /// Threads a state through the list, collecting every intermediate result
+    /// (e.g. prefix sums) into a new list
+    /// #### Params
+    /// - `init` - the initial state
+    /// - `f` - updates the state with an element and returns the result for that element
+    pub fn scan<S, U: std::fmt::Debug, F: FnMut(&mut S, &T) -> U>(
+        &self,
+        init: S,
+        mut f: F,
+    ) -> LinkedList2<U> {
+        let mut state = init;
+        let mut results = Vec::with_capacity(self.size);
+        let _ = self.visit(|item| {
+            results.push(Rc::new(RefCell::new(f(&mut state, item))));
+            ControlFlow::Continue(())
+        });
+        LinkedList2::from_contents(results)
+    }
+
+    /// Clones the elements in `range` and adds the copies to the end of the list
+    /// #### Params
+    /// - `range` - the indices of the elements to copy
+    pub fn extend_from_within<R: RangeBounds<usize>>(
+        &mut self,
+        range: R,
+    ) -> Result<(), ListOperationErr>
+    where
+        T: Clone,
+    {
+        let (start, end) = resolve_range(range, self.size)?;
+
+        let mut copies = Vec::with_capacity(end - start);
+        let mut index = 0;
+        let _ = self.visit(|item| {
+            if index >= end {
+                return ControlFlow::Break(());
+            }
+            if index >= start {
+                copies.push(item.clone());
+            }
+            index += 1;
+            ControlFlow::Continue(())
+        });
+
+        for copy in copies {
+            self.add_raw(copy);
         }
-        // if head
-        else if ptr::eq(
-            cur.clone().ok_or(UNEXPECTED_ERR)?.borrow().content.as_ref(),
-            item.as_ref(),
-        ) {
-            let _ = self.shift();
+        Ok(())
+    }
 
-            self.size -= 1;
-            Ok(())
-        } else {
-            let mut target_node = Err(ListOperationErr::ElementNotFound);
-            // `cur.content` != `item`
-            cur = cur.ok_or(UNEXPECTED_ERR)?.borrow().linked_nodes.1.clone();
-
-            // look for node matching `item`
-            loop {
-                let _cur = cur.clone().ok_or(UNEXPECTED_ERR)?;
-                if ptr::eq(_cur.clone().borrow().content.as_ref(), item.as_ref()) {
-                    target_node = Ok(_cur.clone());
-                    break;
+    /// Moves the element at `index` to the start of the list by relinking its node
+    /// #### Params
+    /// - `index` - the index of the element to move
+    pub fn move_to_front(&mut self, index: usize) -> Result<(), ListOperationErr> {
+        self.move_handle_to_front(&self.handle_at(index)?)
+    }
+
+    /// Moves the element at `index` to the end of the list by relinking its node
+    /// #### Params
+    /// - `index` - the index of the element to move
+    pub fn move_to_back(&mut self, index: usize) -> Result<(), ListOperationErr> {
+        self.move_handle_to_back(&self.handle_at(index)?)
+    }
+
+    /// Moves the node of `handle` to the start of the list, in O(1)
+    /// #### Params
+    /// - `handle` - a handle to a node of this list
+    pub fn move_handle_to_front(&mut self, handle: &NodeHandle<T>) -> Result<(), ListOperationErr> {
+        let node = self.owned_node(handle)?;
+        self.unlink(&node);
+        let head = self.head.clone();
+        self.link_between(node, None, head);
+        Ok(())
+    }
+
+    /// Moves the node of `handle` to the end of the list, in O(1)
+    /// #### Params
+    /// - `handle` - a handle to a node of this list
+    pub fn move_handle_to_back(&mut self, handle: &NodeHandle<T>) -> Result<(), ListOperationErr> {
+        let node = self.owned_node(handle)?;
+        self.unlink(&node);
+        let tail = self.tail.clone();
+        self.link_between(node, tail, None);
+        Ok(())
+    }
+
+    /// Compares the list element-wise with `other`, using `eq` to compare elements
+    /// #### Params
+    /// - `other` - the list to compare against
+    /// - `eq` - returns `true` if its arguments should be considered equal
+    pub fn eq_by<U: std::fmt::Debug, F: FnMut(&T, &U) -> bool>(
+        &self,
+        other: &LinkedList2<U>,
+        mut eq: F,
+    ) -> bool {
+        self.size == other.size
+            && self.cmp_by(other, |a, b| {
+                if eq(a, b) {
+                    Ordering::Equal
+                } else {
+                    Ordering::Less
                 }
+            }) == Ordering::Equal
+    }
 
-                match _cur.clone().borrow().linked_nodes.1.clone() {
-                    Some(nxt) => {
-                        cur.replace(nxt);
+    /// Lexicographically compares the list with `other`, using `cmp` to compare elements
+    /// #### Params
+    /// - `other` - the list to compare against
+    /// - `cmp` - compares an element of this list with an element of `other`
+    pub fn cmp_by<U: std::fmt::Debug, F: FnMut(&T, &U) -> Ordering>(
+        &self,
+        other: &LinkedList2<U>,
+        mut cmp: F,
+    ) -> Ordering {
+        let mut cur = self.head.as_deref();
+        let mut other_cur = other.head.as_deref();
+        loop {
+            match (cur, other_cur) {
+                (None, None) => return Ordering::Equal,
+                (None, Some(_)) => return Ordering::Less,
+                (Some(_), None) => return Ordering::Greater,
+                (Some(c), Some(o)) => {
+                    let (node, other_node) = (ListNode2::peek(c), ListNode2::peek(o));
+                    match cmp(&node.content.borrow(), &other_node.content.borrow()) {
+                        Ordering::Equal => {
+                            cur = node.linked_nodes.1.as_deref();
+                            other_cur = other_node.linked_nodes.1.as_deref();
+                        }
+                        ordering => return ordering,
                     }
-                    None => break,
                 }
             }
+        }
+    }
 
-            let target_node = target_node?;
-
-            if ptr::eq(
-                self.tail.clone().ok_or(UNEXPECTED_ERR)?.as_ref(),
-                target_node.clone().as_ref(),
-            ) {
-                // if tail
-                let _tail = self.tail.clone().ok_or(UNEXPECTED_ERR)?;
-                self.tail.replace(
-                    _tail
-                        .borrow()
-                        .linked_nodes
-                        .0
-                        .clone()
-                        .ok_or(UNEXPECTED_ERR)?,
-                );
-                _tail.borrow_mut().break_link1();
-            } else {
-                let (n0, n1) = target_node.borrow().linked_nodes.clone();
-                Self::link_nodes(n0.ok_or(UNEXPECTED_ERR)?, n1.ok_or(UNEXPECTED_ERR)?);
+    /// Encodes the list as runs of consecutive equal elements
+    /// #### Returns
+    /// a list of `(element, run_length)` pairs
+    pub fn run_length_encode(&self) -> LinkedList2<(T, usize)>
+    where
+        T: PartialEq + Clone,
+    {
+        let mut runs: Vec<(T, usize)> = Vec::new();
+        let _ = self.visit(|item| {
+            match runs.last_mut() {
+                Some((value, count)) if value == item => *count += 1,
+                _ => runs.push((item.clone(), 1)),
             }
+            ControlFlow::Continue(())
+        });
 
-            self.size -= 1;
-            Ok(())
+        LinkedList2::from_contents(
+            runs.into_iter()
+                .map(|run| Rc::new(RefCell::new(run)))
+                .collect(),
+        )
+    }
+
+    /// Expands a list of `(element, run_length)` pairs, as produced by `run_length_encode`
+    /// #### Params
+    /// - `runs` - the runs to expand
+    pub fn run_length_decode(runs: &LinkedList2<(T, usize)>) -> Self
+    where
+        T: Clone,
+    {
+        let mut contents = Vec::new();
+        let _ = runs.visit(|(value, count)| {
+            contents.extend((0..*count).map(|_| Rc::new(RefCell::new(value.clone()))));
+            ControlFlow::Continue(())
+        });
+        LinkedList2::from_contents(contents)
+    }
+
+    /// Builds a new list with a copy of `separator` between every pair of adjacent elements.
+    ///
+    /// The new list shares the elements of this list.
+    /// #### Params
+    /// - `separator` - the element to place between elements
+    pub fn intersperse(&self, separator: T) -> Self
+    where
+        T: Clone,
+    {
+        let mut contents = Vec::with_capacity((2 * self.size).saturating_sub(1));
+        let mut cur = self.head.as_deref();
+        while let Some(c) = cur {
+            let node = ListNode2::peek(c);
+            if !contents.is_empty() {
+                contents.push(Rc::new(RefCell::new(separator.clone())));
+            }
+            contents.push(node.content.clone());
+            cur = node.linked_nodes.1.as_deref();
         }
+        LinkedList2::from_contents(contents)
     }
 
-    fn remove_at(&mut self, index: usize) -> Result<Rc<RefCell<T>>, ListOperationErr> {
-        self.index_check(index)?;
+    /// #### Returns
+    /// how many times each distinct element occurs in the list
+    pub fn counts(&self) -> HashMap<T, usize>
+    where
+        T: Hash + Eq + Clone,
+    {
+        let mut counts = HashMap::new();
+        let _ = self.visit(|item| {
+            match counts.get_mut(item) {
+                Some(count) => *count += 1,
+                None => {
+                    counts.insert(item.clone(), 1);
+                }
+            }
+            ControlFlow::Continue(())
+        });
+        counts
+    }
 
-        if index == 0 {
-            // if head
-            self.shift()
-        } else if index == self.size - 1 {
-            // if tail
-            self.pop()
-        } else {
-            // otherwise...
-            // get node
-            let n = self.get_node_at(index)?;
-            let result = n.borrow().content.clone();
-            let (n0, n1) = n.borrow().linked_nodes.clone();
-            Self::link_nodes(n0.ok_or(UNEXPECTED_ERR)?, n1.ok_or(UNEXPECTED_ERR)?);
+    /// Finds where a sorted list stops satisfying `pred`, stopping at the first failing element.
+    ///
+    /// The list is assumed to be partitioned by `pred` (all matching elements first).
+    /// #### Params
+    /// - `pred` - the predicate the list is partitioned by
+    /// #### Returns
+    /// the index of the first element for which `pred` returns `false`, or the list size
+    pub fn partition_point<P: FnMut(&T) -> bool>(&self, mut pred: P) -> usize {
+        let mut index = 0;
+        let _ = self.visit(|item| {
+            if !pred(item) {
+                return ControlFlow::Break(());
+            }
+            index += 1;
+            ControlFlow::Continue(())
+        });
+        index
+    }
 
-            self.size -= 1;
+    /// Finds the `k`-th smallest element (counting from 0) in expected O(n), without sorting the list
+    /// #### Params
+    /// - `k` - the rank of the element to find
+    pub fn kth_smallest(&self, k: usize) -> Result<Rc<RefCell<T>>, ListOperationErr>
+    where
+        T: Ord,
+    {
+        self.index_check(k)?;
 
-            Ok(result)
+        let mut contents = Vec::with_capacity(self.size);
+        let mut cur = self.head.as_deref();
+        while let Some(c) = cur {
+            let node = ListNode2::peek(c);
+            contents.push(node.content.clone());
+            cur = node.linked_nodes.1.as_deref();
         }
+
+        let (_, kth, _) = contents.select_nth_unstable_by(k, |a, b| a.borrow().cmp(&b.borrow()));
+        Ok(kth.clone())
     }
 
-    fn is_empty(&self) -> bool {
-        self.size < 1
+    /// #### Returns
+    /// the arithmetic mean of the elements
+    pub fn mean(&self) -> Result<f64, ListOperationErr>
+    where
+        T: Numeric,
+    {
+        statistics::mean(&self.numeric_values())
     }
 
-    fn size(&self) -> usize {
-        self.size
+    /// #### Returns
+    /// the median of the elements, found by selection rather than a full sort
+    pub fn median(&self) -> Result<f64, ListOperationErr>
+    where
+        T: Numeric,
+    {
+        statistics::median(self.numeric_values())
+    }
+
+    /// #### Returns
+    /// the population variance of the elements
+    pub fn variance(&self) -> Result<f64, ListOperationErr>
+    where
+        T: Numeric,
+    {
+        statistics::variance(&self.numeric_values())
+    }
+
+    /// Get the elements as `f64`s
+    fn numeric_values(&self) -> Vec<f64>
+    where
+        T: Numeric,
+    {
+        let mut values = Vec::with_capacity(self.size);
+        let _ = self.visit(|item| {
+            values.push(item.to_f64());
+            ControlFlow::Continue(())
+        });
+        values
+    }
+
+    /// #### Params
+    /// - `other` - the list to pair elements with
+    /// #### Returns
+    /// a lazy iterator over every `(a, b)` pair with `a` from this list and `b` from `other`
+    pub fn cartesian_product<U: std::fmt::Debug>(
+        &self,
+        other: &LinkedList2<U>,
+    ) -> CartesianProduct<LinkedList2Iterator<T>, LinkedList2Iterator<U>> {
+        CartesianProduct::new(
+            LinkedList2Iterator::new(self),
+            LinkedList2Iterator::new(other),
+        )
+    }
+
+    /// Pairs up the elements of both lists in order, stopping at the end of the shorter one
+    /// like `Iterator::zip`.
+    ///
+    /// The values are moved out of their `Rc`s, only elements shared elsewhere are cloned.
+    /// #### Params
+    /// - `other` - the list to take the second element of every pair from
+    /// #### Returns
+    /// a list of `(a, b)` pairs
+    pub fn zip<U: Clone + std::fmt::Debug>(self, other: LinkedList2<U>) -> LinkedList2<(T, U)>
+    where
+        T: Clone,
+    {
+        self.into_iter()
+            .zip(other)
+            .map(|(a, b)| (unwrap_or_clone(a), unwrap_or_clone(b)))
+            .collect()
+    }
+
+    /// Picks `n` elements uniformly at random in a single pass (reservoir sampling).
+    ///
+    /// The walk follows the links rather than the stored size, and the sample
+    /// shares the elements of this list, in no particular order.
+    /// #### Params
+    /// - `n` - the number of elements to pick; the whole list is returned if it is shorter
+    /// - `rng` - the source of randomness
+    #[cfg(feature = "rand")]
+    pub fn sample<R: rand::Rng + ?Sized>(&self, n: usize, rng: &mut R) -> Self {
+        let mut reservoir = Vec::new();
+        let mut seen = 0;
+        let mut cur = self.head.as_deref();
+        while let Some(c) = cur {
+            let node = ListNode2::peek(c);
+            if reservoir.len() < n {
+                reservoir.push(node.content.clone());
+            } else {
+                // keep the element with probability n / (seen + 1)
+                let slot = rng.gen_range(0..=seen);
+                if slot < n {
+                    reservoir[slot] = node.content.clone();
+                }
+            }
+            seen += 1;
+            cur = node.linked_nodes.1.as_deref();
+        }
+        Self::from_contents(reservoir)
+    }
+
+    /// Builds a new list with every distinct element of this list, followed by
+    /// the distinct elements of `other` that are not in this list.
+    ///
+    /// The new list shares the elements of both lists, keeping first occurrences in order.
+    /// #### Params
+    /// - `other` - the list to combine with
+    pub fn union(&self, other: &Self) -> Self
+    where
+        T: Hash + Eq,
+    {
+        // elements are not modified while the set is alive
+        #[allow(clippy::mutable_key_type)]
+        let mut seen = HashSet::with_capacity(self.size + other.size);
+        let contents = self
+            .iter_snapshot()
+            .chain(other.iter_snapshot())
+            .filter(|content| seen.insert(ValueKey(content.clone())))
+            .collect();
+        LinkedList2::from_contents(contents)
+    }
+
+    /// Builds a new list with the distinct elements of this list that are also in `other`.
+    ///
+    /// The new list shares the elements of this list, keeping first occurrences in order.
+    /// #### Params
+    /// - `other` - the list to intersect with
+    pub fn intersection(&self, other: &Self) -> Self
+    where
+        T: Hash + Eq,
+    {
+        self.filter_distinct(other, true)
+    }
+
+    /// Builds a new list with the distinct elements of this list that are not in `other`.
+    ///
+    /// The new list shares the elements of this list, keeping first occurrences in order.
+    /// #### Params
+    /// - `other` - the list whose elements are left out
+    pub fn difference(&self, other: &Self) -> Self
+    where
+        T: Hash + Eq,
+    {
+        self.filter_distinct(other, false)
+    }
+
+    /// Collects the distinct elements of this list whose membership in `other` is `in_other`
+    fn filter_distinct(&self, other: &Self, in_other: bool) -> Self
+    where
+        T: Hash + Eq,
+    {
+        // elements are not modified while the sets are alive
+        #[allow(clippy::mutable_key_type)]
+        let other: HashSet<_> = other.iter_snapshot().map(ValueKey).collect();
+        #[allow(clippy::mutable_key_type)]
+        let mut seen = HashSet::with_capacity(self.size);
+        let contents = self
+            .iter_snapshot()
+            .map(ValueKey)
+            .filter(|key| other.contains(key) == in_other && seen.insert(ValueKey(key.0.clone())))
+            .map(|key| key.0)
+            .collect();
+        LinkedList2::from_contents(contents)
+    }
+
+    /// Keeps only the elements for which `keep` returns `true`, unlinking the rest in a single pass
+    /// #### Params
+    /// - `keep` - called with each element, in order
+    /// #### Returns
+    /// Number of elements removed
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut keep: F) -> usize {
+        trace_op!(list = "LinkedList2", op = "retain", size = self.size);
+        self.retain_contents(|content| keep(&content.borrow()))
+    }
+
+    /// Empties the list through an iterator that unlinks each element as it yields it.
+    ///
+    /// Elements the iterator did not get to are removed when it is dropped.
+    pub fn drain(&mut self) -> LinkedList2Drain<'_, T> {
+        LinkedList2Drain { list: self }
+    }
+
+    /// Unlinks the elements for which `filter` returns `true` through an iterator yielding
+    /// them, keeping the rest linked in order.
+    ///
+    /// The elements are only tested as the iterator advances: dropping it early keeps every
+    /// element it did not get to.
+    /// #### Params
+    /// - `filter` - called with each element, in order, and may change it
+    pub fn extract_if<F: FnMut(&mut T) -> bool>(
+        &mut self,
+        filter: F,
+    ) -> LinkedList2ExtractIf<'_, T, F> {
+        LinkedList2ExtractIf {
+            next: self.head.clone(),
+            list: self,
+            filter,
+        }
+    }
+
+    /// Shortens the list to its first `len` elements by cutting the link after the last kept node.
+    ///
+    /// Does nothing if the list holds `len` elements or fewer.
+    /// #### Params
+    /// - `len` - the number of elements to keep
+    pub fn truncate(&mut self, len: usize) {
+        trace_op!(list = "LinkedList2", op = "truncate", len, size = self.size);
+        if len < self.size {
+            // the cut off nodes are unlinked one by one when the detached list is dropped
+            drop(self.split_off(len));
+        }
+    }
+
+    /// Removes every element, unlinking the nodes one by one
+    pub fn clear(&mut self) {
+        self.forget_position();
+        trace_op!(list = "LinkedList2", op = "clear", size = self.size);
+        touch_nodes!(self.size);
+        self.tail = None;
+        let mut cur = self.head.take();
+        while let Some(node) = cur {
+            let mut node = node.borrow_mut();
+            node.linked_nodes.0 = None;
+            cur = node.linked_nodes.1.take();
+            node.retire();
+        }
+        self.size = 0;
+    }
+
+    /// Removes every element equal to an element before it, keeping first occurrences in order
+    /// #### Returns
+    /// Number of elements removed
+    pub fn unique(&mut self) -> usize
+    where
+        T: Hash + Eq,
+    {
+        // elements are not modified while the set is alive
+        #[allow(clippy::mutable_key_type)]
+        let mut seen = HashSet::with_capacity(self.size);
+        self.retain_contents(|content| seen.insert(ValueKey(content.clone())))
+    }
+
+    /// Removes consecutive equal elements, keeping the first of every run
+    /// #### Returns
+    /// Number of elements removed
+    pub fn dedup(&mut self) -> usize
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|current, previous| current == previous)
+    }
+
+    /// Removes every element for which `same(element, previous)` returns `true`, in a single pass,
+    /// where `previous` is the last element kept before it
+    /// #### Returns
+    /// Number of elements removed
+    pub fn dedup_by<F: FnMut(&T, &T) -> bool>(&mut self, mut same: F) -> usize {
+        trace_op!(list = "LinkedList2", op = "dedup", size = self.size);
+        let mut previous: Option<Rc<RefCell<T>>> = None;
+        self.retain_contents(|content| {
+            let duplicate = previous
+                .as_ref()
+                .is_some_and(|previous| same(&content.borrow(), &previous.borrow()));
+            if !duplicate {
+                previous = Some(content.clone());
+            }
+            !duplicate
+        })
+    }
+
+    /// Unlinks every element for which `keep` returns `false`, in a single pass
+    /// #### Returns
+    /// Number of elements removed
+    fn retain_contents<F: FnMut(&Rc<RefCell<T>>) -> bool>(&mut self, mut keep: F) -> usize {
+        self.forget_position();
+        let mut kept = Vec::with_capacity(self.size);
+        let mut removed = 0;
+        let mut cur = self.head.clone();
+        while let Some(c) = cur {
+            cur = c.borrow().linked_nodes.1.clone();
+            if keep(&c.borrow().content) {
+                kept.push(c);
+            } else {
+                let mut node = c.borrow_mut();
+                node.linked_nodes = (None, None);
+                node.retire();
+                removed += 1;
+            }
+        }
+
+        self.relink(kept);
+        removed
+    }
+
+    /// checks whether an element matching `item` is in the list
+    /// #### Params
+    /// - `item` - the item to lookup
+    /// - `strategy` - how elements are matched against `item`
+    pub fn contains_with(&self, item: Rc<RefCell<T>>, strategy: &EqStrategy<T>) -> bool {
+        self.index_with(&item, strategy).is_some()
+    }
+
+    /// removes the first element matching `item`
+    /// #### Params
+    /// - `item` - the item to lookup
+    /// - `strategy` - how elements are matched against `item`
+    /// #### Returns
+    /// a reference to the removed element
+    pub fn remove_with(
+        &mut self,
+        item: Rc<RefCell<T>>,
+        strategy: &EqStrategy<T>,
+    ) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        let index = self
+            .index_with(&item, strategy)
+            .ok_or(ListOperationErr::ElementNotFound)?;
+        self.remove_at(index)
+    }
+
+    /// Get the index of the first element equal to `item`
+    /// #### Params
+    /// - `item` - the value to lookup
+    pub fn index_of(&self, item: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.iter().position(|content| *content.borrow() == *item)
+    }
+
+    /// checks whether an element equal to `item` is in the list
+    /// #### Params
+    /// - `item` - the value to lookup
+    pub fn contains_value(&self, item: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.index_of(item).is_some()
+    }
+
+    /// removes the first element equal to `item`
+    /// #### Params
+    /// - `item` - the value to lookup
+    /// #### Returns
+    /// a reference to the removed element
+    pub fn remove_value(&mut self, item: &T) -> Result<Rc<RefCell<T>>, ListOperationErr>
+    where
+        T: PartialEq,
+    {
+        let index = self
+            .index_of(item)
+            .ok_or(ListOperationErr::ElementNotFound)?;
+        self.remove_at(index)
+    }
+
+    /// removes every element equal to `item`, in a single pass
+    /// #### Params
+    /// - `item` - the value to remove
+    /// #### Returns
+    /// Number of elements removed
+    pub fn remove_all(&mut self, item: &T) -> usize
+    where
+        T: PartialEq,
+    {
+        trace_op!(list = "LinkedList2", op = "remove_all", size = self.size);
+        self.retain_contents(|content| *content.borrow() != *item)
+    }
+
+    /// #### Params
+    /// - `item` - the value to count
+    /// #### Returns
+    /// Number of elements equal to `item`
+    pub fn count(&self, item: &T) -> usize
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(|content| *content.borrow() == *item)
+            .count()
+    }
+
+    /// Get the index of the first element matching `item`
+    fn index_with(&self, item: &Rc<RefCell<T>>, strategy: &EqStrategy<T>) -> Option<usize> {
+        self.iter()
+            .position(|content| strategy.matches(content, item))
+    }
+
+    /// Consumes the list
+    /// #### Returns
+    /// a read-only, cheaply clonable view of the list
+    pub fn freeze(self) -> FrozenList<Self> {
+        FrozenList::new(self)
+    }
+
+    /// #### Returns
+    /// an iterator over the elements the list holds right now.
+    ///
+    /// The elements are captured up front, so adding or removing elements
+    /// afterwards (including through `NodeHandle`s) does not affect what the iterator yields.
+    pub fn iter_snapshot(&self) -> SnapshotIterator<T> {
+        SnapshotIterator::new(self.iter().cloned().collect())
+    }
+
+    /// #### Returns
+    /// an iterator over references to the elements, without consuming the list
+    pub fn iter(&self) -> LinkedList2Iter<'_, T> {
+        LinkedList2Iter {
+            front: self.head.as_deref(),
+            back: self.tail.as_deref(),
+            remaining: self.size,
+        }
+    }
+
+    /// #### Returns
+    /// an iterator over mutable borrows of the elements, without consuming the list.
+    ///
+    /// Panics like `RefCell::borrow_mut` if an element is borrowed elsewhere when it is reached.
+    pub fn iter_mut(&mut self) -> LinkedList2IterMut<'_, T> {
+        LinkedList2IterMut {
+            front: self.head.as_deref(),
+            back: self.tail.as_deref(),
+            remaining: self.size,
+        }
+    }
+
+    /// #### Returns
+    /// an iterator over references to the elements, from the tail to the head
+    pub fn iter_rev(&self) -> Rev<LinkedList2Iter<'_, T>> {
+        self.iter().rev()
+    }
+
+    /// #### Returns
+    /// a `Vec<T>` with a clone of every element, in order
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().map(|item| item.borrow().clone()).collect()
+    }
+
+    /// Consumes the list, draining its elements into a sorted `Vec<T>`.
+    ///
+    /// Elements that are still shared elsewhere are cloned out of their `RefCell`.
+    pub fn into_sorted_vec(mut self) -> Vec<T>
+    where
+        T: Ord + Clone,
+    {
+        let mut values = Vec::with_capacity(self.size);
+        // unlinking node by node leaves the list holding no other reference to the contents
+        while let Ok(item) = self.shift() {
+            values.push(unwrap_or_clone(item));
+        }
+        values.sort();
+        values
+    }
+
+    /// Sorts the list in ascending order.
+    ///
+    /// The sort is a stable bottom-up merge sort that relinks the existing nodes
+    /// instead of moving their contents.
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
+
+    /// Sorts the list with a comparator function, see `sort`
+    /// #### Params
+    /// - `compare` - orders two elements
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
+        self.forget_position();
+        trace_op!(list = "LinkedList2", op = "sort_by", size = self.size);
+        // bins[i] holds a sorted run of 2^i nodes (or nothing), earlier runs in higher bins
+        let mut bins: Vec<Option<Rc<RefCell<ListNode2<T>>>>> = Vec::new();
+        let mut cur = self.head.take();
+        while let Some(c) = cur {
+            cur = c.borrow_mut().linked_nodes.1.take();
+            let mut run = Some(c);
+            let mut i = 0;
+            while let Some(bin) = bins.get_mut(i).and_then(Option::take) {
+                run = Self::merge_runs(Some(bin), run, &mut compare);
+                i += 1;
+            }
+            match bins.get_mut(i) {
+                Some(slot) => *slot = run,
+                None => bins.push(run),
+            }
+        }
+
+        let mut sorted = None;
+        for bin in bins.into_iter().filter(Option::is_some) {
+            sorted = Self::merge_runs(bin, sorted, &mut compare);
+        }
+        // the merges only maintain the forward links, restore the back links and the tail
+        let mut prev: Link2<T> = None;
+        let mut cur = sorted.clone();
+        while let Some(c) = cur {
+            c.borrow_mut().linked_nodes.0 = prev.as_ref().map(Rc::downgrade);
+            cur = c.borrow().linked_nodes.1.clone();
+            prev = Some(c);
+        }
+        self.head = sorted;
+        self.tail = prev;
+    }
+
+    /// add an item at its sorted position, after any equal elements.
+    ///
+    /// The list is expected to be sorted already, see `is_sorted`.
+    /// #### Params
+    /// - `item` - a reference to the item to insert
+    /// #### Returns
+    /// the index the item was inserted at
+    pub fn insert_sorted(&mut self, item: Rc<RefCell<T>>) -> usize
+    where
+        T: Ord,
+    {
+        self.insert_sorted_by(item, T::cmp)
+    }
+
+    /// add an item at its sorted position with a comparator function, see `insert_sorted`
+    /// #### Params
+    /// - `item` - a reference to the item to insert
+    /// - `compare` - orders two elements, the list must be sorted by it
+    /// #### Returns
+    /// the index the item was inserted at
+    pub fn insert_sorted_by<F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        item: Rc<RefCell<T>>,
+        mut compare: F,
+    ) -> usize {
+        let index = self
+            .position(|content| compare(content, &item.borrow()) == Ordering::Greater)
+            .unwrap_or(self.size);
+        // `index` is at most `size`, which `insert_at` appends at
+        let _ = self.insert_at(item, index);
+        index
+    }
+
+    /// #### Returns
+    /// `true` if the elements are in ascending order, which an empty list always is
+    pub fn is_sorted(&self) -> bool
+    where
+        T: Ord,
+    {
+        self.is_sorted_by(T::cmp)
+    }
+
+    /// #### Params
+    /// - `compare` - orders two elements
+    /// #### Returns
+    /// `true` if no element is ordered after the next one by `compare`
+    pub fn is_sorted_by<F: FnMut(&T, &T) -> Ordering>(&self, mut compare: F) -> bool {
+        self.iter()
+            .zip(self.iter().skip(1))
+            .all(|(a, b)| compare(&a.borrow(), &b.borrow()) != Ordering::Greater)
+    }
+
+    /// Merges two sorted lists into one sorted list in O(n + m), relinking the nodes of both.
+    ///
+    /// The merge is stable: of equal elements, those of this list come first.
+    /// #### Params
+    /// - `other` - the sorted list to merge in
+    /// #### Returns
+    /// a sorted list holding the elements of both lists
+    pub fn merge(self, other: Self) -> Self
+    where
+        T: Ord,
+    {
+        self.merge_by(other, T::cmp)
+    }
+
+    /// Merges two lists sorted by a comparator function, see `merge`
+    /// #### Params
+    /// - `other` - the list to merge in
+    /// - `compare` - orders two elements, both lists must be sorted by it
+    pub fn merge_by<F: FnMut(&T, &T) -> Ordering>(
+        mut self,
+        mut other: Self,
+        mut compare: F,
+    ) -> Self {
+        self.forget_position();
+        other.forget_position();
+        trace_op!(
+            list = "LinkedList2",
+            op = "merge_by",
+            size = self.size + other.size
+        );
+        let merged = Self::merge_runs(self.head.take(), other.head.take(), &mut compare);
+        other.tail = None;
+        self.size += mem::take(&mut other.size);
+
+        // the merges only maintain the forward links, restore the back links and the tail
+        let mut prev: Link2<T> = None;
+        let mut cur = merged.clone();
+        while let Some(c) = cur {
+            c.borrow_mut().linked_nodes.0 = prev.as_ref().map(Rc::downgrade);
+            cur = c.borrow().linked_nodes.1.clone();
+            prev = Some(c);
+        }
+        self.head = merged;
+        self.tail = prev;
+        self
+    }
+
+    /// Sorts the list by the key `f` extracts from each element, see `sort`
+    /// #### Params
+    /// - `f` - extracts the key to sort by from an element
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut f: F) {
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Merges two sorted runs of forward-linked nodes into one, keeping `a` first on ties
+    fn merge_runs<F: FnMut(&T, &T) -> Ordering>(
+        mut a: Option<Rc<RefCell<ListNode2<T>>>>,
+        mut b: Option<Rc<RefCell<ListNode2<T>>>>,
+        compare: &mut F,
+    ) -> Option<Rc<RefCell<ListNode2<T>>>> {
+        let mut head = None;
+        let mut tail: Option<Rc<RefCell<ListNode2<T>>>> = None;
+        loop {
+            let next = match (a.take(), b.take()) {
+                (Some(x), Some(y)) => {
+                    let y_first =
+                        compare(&y.borrow().content.borrow(), &x.borrow().content.borrow())
+                            == Ordering::Less;
+                    if y_first {
+                        b = y.borrow_mut().linked_nodes.1.take();
+                        a = Some(x);
+                        y
+                    } else {
+                        a = x.borrow_mut().linked_nodes.1.take();
+                        b = Some(y);
+                        x
+                    }
+                }
+                // one run is used up, the rest of the other is already linked
+                (Some(rest), None) | (None, Some(rest)) => rest,
+                (None, None) => break,
+            };
+            match &tail {
+                Some(t) => t.borrow_mut().linked_nodes.1 = Some(next.clone()),
+                None => head = Some(next.clone()),
+            }
+            tail = Some(next);
+        }
+        head
+    }
+
+    /// Sorts the list with a key extraction function, computing the key only once per element.
+    ///
+    /// The sort is stable and relinks the existing nodes instead of moving their contents.
+    /// #### Params
+    /// - `f` - extracts the key to sort by from an element
+    pub fn sort_by_cached_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut f: F) {
+        trace_op!(
+            list = "LinkedList2",
+            op = "sort_by_cached_key",
+            size = self.size
+        );
+        let mut keyed = Vec::with_capacity(self.size);
+        let mut cur = self.head.clone();
+        while let Some(c) = cur {
+            let key = f(&c.borrow().content.borrow());
+            cur = c.borrow().linked_nodes.1.clone();
+            keyed.push((key, c));
+        }
+
+        keyed.sort_by(|a, b| a.0.cmp(&b.0));
+        self.relink(keyed.into_iter().map(|(_, node)| node).collect());
+    }
+
+    /// Relinks `nodes` in the given order and makes them the content of the list
+    fn relink(&mut self, nodes: Vec<Rc<RefCell<ListNode2<T>>>>) {
+        self.forget_position();
+        self.head.take();
+        self.tail.take();
+        self.size = nodes.len();
+
+        for node in nodes {
+            node.borrow_mut().linked_nodes = (None, None);
+            match self.tail.take() {
+                Some(prev) => {
+                    node.borrow_mut().linked_nodes.0 = Some(Rc::downgrade(&prev));
+                    prev.borrow_mut().linked_nodes.1 = Some(node.clone());
+                }
+                None => self.head = Some(node.clone()),
+            }
+            self.tail = Some(node);
+        }
+    }
+
+    /// add an item to the end of the list
+    /// #### Params
+    /// - `item` - a reference to the item to add
+    /// #### Returns
+    /// a handle to the node holding `item`
+    pub fn add_with_handle(&mut self, item: Rc<RefCell<T>>) -> NodeHandle<T> {
+        trace_op!(list = "LinkedList2", op = "add", size = self.size);
+        let node = ListNode2::new(item);
+        let tail = self.tail.clone();
+        self.link_between(node.clone(), tail, None);
+        NodeHandle::new(&node, self.id())
+    }
+
+    /// add an item to the front of the list
+    /// #### Params
+    /// - `item` - a reference to the item to add
+    /// #### Returns
+    /// a handle to the node holding `item`
+    pub fn push_front_with_handle(&mut self, item: Rc<RefCell<T>>) -> NodeHandle<T> {
+        trace_op!(list = "LinkedList2", op = "push_front", size = self.size);
+        let node = ListNode2::new(item);
+        let head = self.head.clone();
+        self.link_between(node.clone(), None, head);
+        NodeHandle::new(&node, self.id())
+    }
+
+    /// #### Params
+    /// - `index` - the index to lookup
+    /// #### Returns
+    /// a handle to the node at `index`
+    pub fn handle_at(&self, index: usize) -> Result<NodeHandle<T>, ListOperationErr> {
+        Ok(NodeHandle::new(&self.get_node_at(index)?, self.id()))
+    }
+
+    /// #### Returns
+    /// an iterator over handles to every node of the list, from head to tail
+    pub fn handles(&self) -> LinkedList2Handles<T> {
+        LinkedList2Handles {
+            current: self.head.clone(),
+            list: self.id(),
+        }
+    }
+
+    /// insert an item right after the node of `handle`
+    /// #### Params
+    /// - `handle` - a handle to a node of this list
+    /// - `item` - a reference to the item to insert
+    /// #### Returns
+    /// a handle to the node holding `item`
+    pub fn insert_after(
+        &mut self,
+        handle: &NodeHandle<T>,
+        item: Rc<RefCell<T>>,
+    ) -> Result<NodeHandle<T>, ListOperationErr> {
+        trace_op!(list = "LinkedList2", op = "insert_after", size = self.size);
+        let node = self.owned_node(handle)?;
+        let new_node = ListNode2::new(item);
+        let next = node.borrow().linked_nodes.1.clone();
+
+        self.link_between(new_node.clone(), Some(node), next);
+        Ok(NodeHandle::new(&new_node, self.id()))
+    }
+
+    /// insert an item right before the node of `handle`
+    /// #### Params
+    /// - `handle` - a handle to a node of this list
+    /// - `item` - a reference to the item to insert
+    /// #### Returns
+    /// a handle to the node holding `item`
+    pub fn insert_before(
+        &mut self,
+        handle: &NodeHandle<T>,
+        item: Rc<RefCell<T>>,
+    ) -> Result<NodeHandle<T>, ListOperationErr> {
+        trace_op!(list = "LinkedList2", op = "insert_before", size = self.size);
+        let node = self.owned_node(handle)?;
+        let new_node = ListNode2::new(item);
+        let prev = node.borrow().prev_node();
+
+        self.link_between(new_node.clone(), prev, Some(node));
+        Ok(NodeHandle::new(&new_node, self.id()))
+    }
+
+    /// removes the node of `handle` from the list
+    /// #### Params
+    /// - `handle` - a handle to a node of this list
+    /// #### Returns
+    /// a reference to the item held by the removed node
+    pub fn remove_handle(
+        &mut self,
+        handle: &NodeHandle<T>,
+    ) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        trace_op!(list = "LinkedList2", op = "remove_handle", size = self.size);
+        let node = self.owned_node(handle)?;
+        Ok(self.remove_node(&node))
+    }
+
+    /// #### Returns
+    /// a cursor pointing at the head of the list
+    pub fn cursor(&self) -> Cursor<'_, T> {
+        Cursor {
+            current: self.head.as_deref(),
+            index: 0,
+            list: self,
+        }
+    }
+
+    /// #### Returns
+    /// a cursor pointing at the head of the list, able to insert and remove around it
+    pub fn cursor_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            current: self.head.clone(),
+            index: 0,
+            list: self,
+        }
+    }
+
+    /// Moves every node of `other` between the adjacent nodes `prev` and `next`, leaving `other` empty
+    fn splice_between(&mut self, other: &mut Self, prev: Link2<T>, next: Link2<T>) {
+        self.forget_position();
+        other.forget_position();
+        other.forget_handles();
+        let (head, tail) = match (other.head.take(), other.tail.take()) {
+            (Some(head), Some(tail)) => (head, tail),
+            // nothing to move
+            _ => return,
+        };
+        touch_nodes!(2 + prev.is_some() as u8 + next.is_some() as u8);
+        match &prev {
+            Some(prev) => prev.borrow_mut().linked_nodes.1 = Some(head.clone()),
+            None => self.head = Some(head.clone()),
+        }
+        match &next {
+            Some(next) => next.borrow_mut().linked_nodes.0 = Some(Rc::downgrade(&tail)),
+            None => self.tail = Some(tail.clone()),
+        }
+        head.borrow_mut().linked_nodes.0 = prev.as_ref().map(Rc::downgrade);
+        tail.borrow_mut().linked_nodes.1 = next;
+        self.size += other.size;
+        other.size = 0;
+    }
+}
+
+impl<A: Clone + std::fmt::Debug, B: Clone + std::fmt::Debug> LinkedList2<(A, B)> {
+    /// Splits a list of pairs into a list of the first and a list of the second elements,
+    /// the reverse of `zip`.
+    ///
+    /// The values are moved out of their `Rc`s, only pairs shared elsewhere are cloned.
+    /// #### Returns
+    /// `(firsts, seconds)`
+    pub fn unzip(self) -> (LinkedList2<A>, LinkedList2<B>) {
+        self.into_iter().map(unwrap_or_clone).unzip()
+    }
+}
+
+/// Iterator over references to the elements of a `LinkedList2`, see `LinkedList2::iter`
+pub struct LinkedList2Iter<'a, T> {
+    front: Option<&'a RefCell<ListNode2<T>>>,
+    back: Option<&'a RefCell<ListNode2<T>>>,
+    /// elements between `front` and `back`, so the ends stop when they meet
+    remaining: usize,
+}
+
+impl<'a, T: std::fmt::Debug> Iterator for LinkedList2Iter<'a, T> {
+    type Item = &'a Rc<RefCell<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = ListNode2::peek(self.front?);
+        self.front = node.linked_nodes.1.as_deref();
+        self.remaining -= 1;
+        Some(&node.content)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T: std::fmt::Debug> DoubleEndedIterator for LinkedList2Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = ListNode2::peek(self.back?);
+        self.back = node.prev_ref();
+        self.remaining -= 1;
+        Some(&node.content)
+    }
+}
+
+impl<'a, T: std::fmt::Debug> ExactSizeIterator for LinkedList2Iter<'a, T> {}
+
+/// Iterator over mutable borrows of the elements of a `LinkedList2`, see `LinkedList2::iter_mut`
+pub struct LinkedList2IterMut<'a, T> {
+    front: Option<&'a RefCell<ListNode2<T>>>,
+    back: Option<&'a RefCell<ListNode2<T>>>,
+    /// elements between `front` and `back`, so the ends stop when they meet
+    remaining: usize,
+}
+
+impl<'a, T: std::fmt::Debug> Iterator for LinkedList2IterMut<'a, T> {
+    type Item = RefMut<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = ListNode2::peek(self.front?);
+        self.front = node.linked_nodes.1.as_deref();
+        self.remaining -= 1;
+        Some(node.content.borrow_mut())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T: std::fmt::Debug> DoubleEndedIterator for LinkedList2IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = ListNode2::peek(self.back?);
+        self.back = node.prev_ref();
+        self.remaining -= 1;
+        Some(node.content.borrow_mut())
+    }
+}
+
+impl<'a, T: std::fmt::Debug> ExactSizeIterator for LinkedList2IterMut<'a, T> {}
+
+#[derive(Debug)]
+pub struct LinkedList2Iterator<T> {
+    front: Link2<T>,
+    back: Link2<T>,
+    /// elements between `front` and `back`, so the ends stop when they meet
+    remaining: usize,
+}
+
+impl<T: std::fmt::Debug> LinkedList2Iterator<T> {
+    /// an iterator over the elements `list` holds right now
+    fn new(list: &LinkedList2<T>) -> Self {
+        LinkedList2Iterator {
+            front: list.head.clone(),
+            back: list.tail.clone(),
+            remaining: list.size,
+        }
+    }
+}
+
+impl<T: std::fmt::Debug> Clone for LinkedList2Iterator<T> {
+    fn clone(&self) -> Self {
+        Self {
+            front: self.front.clone(),
+            back: self.back.clone(),
+            remaining: self.remaining,
+        }
+    }
+}
+
+impl<T: std::fmt::Debug> Iterator for LinkedList2Iterator<T> {
+    type Item = Rc<RefCell<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let c = self.front.take()?;
+        self.front = c.borrow().linked_nodes.1.clone();
+        self.remaining -= 1;
+        let content = c.borrow().content.clone();
+        Some(content)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: std::fmt::Debug> DoubleEndedIterator for LinkedList2Iterator<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let c = self.back.take()?;
+        self.back = c.borrow().prev_node();
+        self.remaining -= 1;
+        let content = c.borrow().content.clone();
+        Some(content)
+    }
+}
+
+impl<T: std::fmt::Debug> ExactSizeIterator for LinkedList2Iterator<T> {}
+
+/// Iterator over handles to the nodes of a `LinkedList2`
+pub struct LinkedList2Handles<T> {
+    current: Link2<T>,
+    list: u64,
+}
+
+impl<T> Iterator for LinkedList2Handles<T> {
+    type Item = NodeHandle<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let c = self.current.take()?;
+        self.current = c.borrow().linked_nodes.1.clone();
+        Some(NodeHandle::new(&c, self.list))
+    }
+}
+
+/// ### Summary
+/// A read-only position in a `LinkedList2`, moved one node at a time.
+///
+/// Past the tail (and before the head) the cursor points at a "ghost" position
+/// without an element, from which it wraps around to the other end.
+pub struct Cursor<'a, T: std::fmt::Debug> {
+    list: &'a LinkedList2<T>,
+    current: Option<&'a RefCell<ListNode2<T>>>,
+    index: usize,
+}
+
+impl<'a, T: std::fmt::Debug> Cursor<'a, T> {
+    /// #### Returns
+    /// the index of the current element, `None` at the ghost position
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    /// #### Returns
+    /// a reference to the current element, `None` at the ghost position
+    pub fn current(&self) -> Option<&'a Rc<RefCell<T>>> {
+        self.current.map(|c| &ListNode2::peek(c).content)
+    }
+
+    /// #### Returns
+    /// a reference to the element after the current one
+    pub fn peek_next(&self) -> Option<&'a Rc<RefCell<T>>> {
+        let next = match self.current {
+            Some(c) => ListNode2::peek(c).linked_nodes.1.as_deref(),
+            None => self.list.head.as_deref(),
+        };
+        next.map(|n| &ListNode2::peek(n).content)
+    }
+
+    /// #### Returns
+    /// a reference to the element before the current one
+    pub fn peek_prev(&self) -> Option<&'a Rc<RefCell<T>>> {
+        let prev = match self.current {
+            Some(c) => ListNode2::peek(c).prev_ref(),
+            None => self.list.tail.as_deref(),
+        };
+        prev.map(|p| &ListNode2::peek(p).content)
+    }
+
+    /// Moves to the next element, or from the tail to the ghost position
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(c) => {
+                self.current = ListNode2::peek(c).linked_nodes.1.as_deref();
+                self.index += 1;
+            }
+            None => {
+                self.current = self.list.head.as_deref();
+                self.index = 0;
+            }
+        }
+    }
+
+    /// Moves to the previous element, or from the head to the ghost position
+    pub fn move_prev(&mut self) {
+        match self.current {
+            Some(c) => {
+                self.current = ListNode2::peek(c).prev_ref();
+                self.index = self.index.saturating_sub(1);
+            }
+            None => {
+                self.current = self.list.tail.as_deref();
+                self.index = self.list.size.saturating_sub(1);
+            }
+        }
+    }
+}
+
+/// ### Summary
+/// A position in a `LinkedList2` that can insert and remove elements around itself in O(1).
+///
+/// Like `Cursor`, it has a ghost position past the tail, where `insert_before` adds
+/// to the end of the list and `insert_after` to its front.
+pub struct CursorMut<'a, T: std::fmt::Debug> {
+    list: &'a mut LinkedList2<T>,
+    current: Link2<T>,
+    index: usize,
+}
+
+impl<'a, T: std::fmt::Debug> CursorMut<'a, T> {
+    /// #### Returns
+    /// the index of the current element, `None` at the ghost position
+    pub fn index(&self) -> Option<usize> {
+        self.current.as_ref().map(|_| self.index)
+    }
+
+    /// #### Returns
+    /// a reference to the current element, `None` at the ghost position
+    pub fn current(&self) -> Option<Rc<RefCell<T>>> {
+        self.current.as_ref().map(|c| c.borrow().content.clone())
+    }
+
+    /// #### Returns
+    /// a reference to the element after the current one
+    pub fn peek_next(&self) -> Option<Rc<RefCell<T>>> {
+        let next = match &self.current {
+            Some(c) => c.borrow().linked_nodes.1.clone(),
+            None => self.list.head.clone(),
+        };
+        next.map(|n| n.borrow().content.clone())
+    }
+
+    /// #### Returns
+    /// a reference to the element before the current one
+    pub fn peek_prev(&self) -> Option<Rc<RefCell<T>>> {
+        let prev = match &self.current {
+            Some(c) => c.borrow().prev_node(),
+            None => self.list.tail.clone(),
+        };
+        prev.map(|p| p.borrow().content.clone())
+    }
+
+    /// Moves to the next element, or from the tail to the ghost position
+    pub fn move_next(&mut self) {
+        match self.current.take() {
+            Some(c) => {
+                self.current = c.borrow().linked_nodes.1.clone();
+                self.index += 1;
+            }
+            None => {
+                self.current = self.list.head.clone();
+                self.index = 0;
+            }
+        }
+    }
+
+    /// Moves to the previous element, or from the head to the ghost position
+    pub fn move_prev(&mut self) {
+        match self.current.take() {
+            Some(c) => {
+                self.current = c.borrow().prev_node();
+                self.index = self.index.saturating_sub(1);
+            }
+            None => {
+                self.current = self.list.tail.clone();
+                self.index = self.list.size.saturating_sub(1);
+            }
+        }
+    }
+
+    /// insert an item right before the current element, or at the end at the ghost position
+    /// #### Params
+    /// - `item` - a reference to the item to insert
+    pub fn insert_before(&mut self, item: Rc<RefCell<T>>) {
+        let node = ListNode2::new(item);
+        match &self.current {
+            Some(c) => {
+                let prev = c.borrow().prev_node();
+                self.list.link_between(node, prev, Some(c.clone()));
+                self.index += 1;
+            }
+            None => {
+                let tail = self.list.tail.clone();
+                self.list.link_between(node, tail, None);
+            }
+        }
+    }
+
+    /// insert an item right after the current element, or at the front at the ghost position
+    /// #### Params
+    /// - `item` - a reference to the item to insert
+    pub fn insert_after(&mut self, item: Rc<RefCell<T>>) {
+        let node = ListNode2::new(item);
+        match &self.current {
+            Some(c) => {
+                let next = c.borrow().linked_nodes.1.clone();
+                self.list.link_between(node, Some(c.clone()), next);
+            }
+            None => {
+                let head = self.list.head.clone();
+                self.list.link_between(node, None, head);
+            }
+        }
+    }
+
+    /// Removes the current element and moves to the next one
+    /// #### Returns
+    /// a reference to the removed item, `None` at the ghost position
+    pub fn remove_current(&mut self) -> Option<Rc<RefCell<T>>> {
+        let c = self.current.take()?;
+        self.current = c.borrow().linked_nodes.1.clone();
+        Some(self.list.remove_node(&c))
+    }
+
+    /// Moves every element of `other` right before the current element
+    /// (or to the end at the ghost position), leaving `other` empty
+    /// #### Params
+    /// - `other` - the list to move elements from
+    pub fn splice_before(&mut self, other: &mut LinkedList2<T>) {
+        match &self.current {
+            Some(c) => {
+                let prev = c.borrow().prev_node();
+                self.index += other.size;
+                self.list.splice_between(other, prev, Some(c.clone()));
+            }
+            None => {
+                let tail = self.list.tail.clone();
+                self.list.splice_between(other, tail, None);
+            }
+        }
+    }
+
+    /// Moves every element of `other` right after the current element
+    /// (or to the front at the ghost position), leaving `other` empty
+    /// #### Params
+    /// - `other` - the list to move elements from
+    pub fn splice_after(&mut self, other: &mut LinkedList2<T>) {
+        match &self.current {
+            Some(c) => {
+                let next = c.borrow().linked_nodes.1.clone();
+                self.list.splice_between(other, Some(c.clone()), next);
+            }
+            None => {
+                let head = self.list.head.clone();
+                self.list.splice_between(other, None, head);
+            }
+        }
+    }
+
+    /// #### Returns
+    /// a read-only cursor at the same position, borrowing this one
+    pub fn as_cursor(&self) -> Cursor<'_, T> {
+        Cursor {
+            list: self.list,
+            current: self.current.as_deref(),
+            index: self.index,
+        }
+    }
+}
+
+impl<T: std::fmt::Debug> IntoIterator for LinkedList2<T> {
+    type Item = Rc<RefCell<T>>;
+
+    type IntoIter = LinkedList2IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        LinkedList2IntoIter { list: self }
+    }
+}
+
+impl<'a, T: std::fmt::Debug> IntoIterator for &'a LinkedList2<T> {
+    type Item = &'a Rc<RefCell<T>>;
+
+    type IntoIter = LinkedList2Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: std::fmt::Debug> IntoIterator for &'a mut LinkedList2<T> {
+    type Item = RefMut<'a, T>;
+
+    type IntoIter = LinkedList2IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for LinkedList2<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_elements(f, self.size, self.iter(), T::fmt)
+    }
+}
+
+impl<T: std::fmt::Debug + std::fmt::Display> std::fmt::Display for LinkedList2<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_elements(f, self.size, self.iter(), <T as std::fmt::Display>::fmt)
+    }
+}
+
+/// Lists are equal if they hold equal elements in the same order
+impl<T: std::fmt::Debug + PartialEq> PartialEq for LinkedList2<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.eq_by(other, |a, b| a == b)
+    }
+}
+
+impl<T: std::fmt::Debug + Eq> Eq for LinkedList2<T> {}
+
+/// Hashes the size followed by every element, consistent with `PartialEq`
+impl<T: std::fmt::Debug + Hash> Hash for LinkedList2<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.size.hash(state);
+        let _ = self.visit(|item| {
+            item.hash(state);
+            ControlFlow::Continue(())
+        });
+    }
+}
+
+/// A shallow copy: the clone links its own nodes, but shares every `Rc<RefCell<T>>` with this
+/// list, so changing an element through one list shows in the other. `deep_clone` copies the
+/// elements too.
+impl<T: std::fmt::Debug> Clone for LinkedList2<T> {
+    fn clone(&self) -> Self {
+        let mut clone = LinkedList2::new();
+        let mut cur = self.head.clone();
+        while let Some(c) = cur {
+            clone.add(c.clone().borrow().content.clone());
+            cur = c.borrow().linked_nodes.1.clone();
+        }
+        clone
+    }
+}
+
+/// Iterator over consecutive sublists of a `LinkedList2`, see `LinkedList2::chunks`
+pub struct LinkedList2Chunks<T: std::fmt::Debug> {
+    list: LinkedList2<T>,
+    size: usize,
+}
+
+impl<T: std::fmt::Debug> Iterator for LinkedList2Chunks<T> {
+    type Item = LinkedList2<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.list.is_empty() {
+            return None;
+        }
+        if self.list.size <= self.size {
+            return Some(mem::take(&mut self.list));
+        }
+        let rest = self.list.detach_from(self.size).ok()?;
+        Some(mem::replace(&mut self.list, rest))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let chunks = self.list.size.div_ceil(self.size);
+        (chunks, Some(chunks))
+    }
+}
+
+impl<T: std::fmt::Debug> ExactSizeIterator for LinkedList2Chunks<T> {}
+
+/// Iterator moving the elements out of a `LinkedList2`, unlinking each one as it is yielded
+pub struct LinkedList2IntoIter<T: std::fmt::Debug> {
+    list: LinkedList2<T>,
+}
+
+impl<T: std::fmt::Debug> Clone for LinkedList2IntoIter<T> {
+    fn clone(&self) -> Self {
+        Self {
+            list: self.list.clone(),
+        }
+    }
+}
+
+impl<T: std::fmt::Debug> Iterator for LinkedList2IntoIter<T> {
+    type Item = Rc<RefCell<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.shift().ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.size, Some(self.list.size))
+    }
+}
+
+impl<T: std::fmt::Debug> DoubleEndedIterator for LinkedList2IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop().ok()
+    }
+}
+
+impl<T: std::fmt::Debug> ExactSizeIterator for LinkedList2IntoIter<T> {}
+
+/// Iterator removing the elements of a `LinkedList2`, see `LinkedList2::drain`
+pub struct LinkedList2Drain<'a, T: std::fmt::Debug> {
+    list: &'a mut LinkedList2<T>,
+}
+
+impl<'a, T: std::fmt::Debug> Iterator for LinkedList2Drain<'a, T> {
+    type Item = Rc<RefCell<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.shift().ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.size, Some(self.list.size))
+    }
+}
+
+impl<'a, T: std::fmt::Debug> DoubleEndedIterator for LinkedList2Drain<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop().ok()
+    }
+}
+
+impl<'a, T: std::fmt::Debug> ExactSizeIterator for LinkedList2Drain<'a, T> {}
+
+/// Iterator removing the elements of a `LinkedList2` that match a predicate, see
+/// `LinkedList2::extract_if`
+pub struct LinkedList2ExtractIf<'a, T: std::fmt::Debug, F: FnMut(&mut T) -> bool> {
+    list: &'a mut LinkedList2<T>,
+    /// the next node to test
+    next: Link2<T>,
+    filter: F,
+}
+
+impl<'a, T: std::fmt::Debug, F: FnMut(&mut T) -> bool> Iterator for LinkedList2ExtractIf<'a, T, F> {
+    type Item = Rc<RefCell<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(cur) = self.next.take() {
+            self.next = cur.borrow().linked_nodes.1.clone();
+            if (self.filter)(&mut cur.borrow().content.borrow_mut()) {
+                return Some(self.list.remove_node(&cur));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.list.size))
+    }
+}
+
+impl<T: std::fmt::Debug> Drop for LinkedList2<T> {
+    fn drop(&mut self) {
+        // the back links keep every node alive until they are broken, and dropping the
+        // head would drop every following node recursively
+        self.clear();
+    }
+}
+
+impl<'a, T: std::fmt::Debug> Drop for LinkedList2Drain<'a, T> {
+    fn drop(&mut self) {
+        while self.list.shift().is_ok() {}
+    }
+}
+
+impl<T: std::fmt::Debug> FromIterator<T> for LinkedList2<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        LinkedList2::from_contents(
+            iter.into_iter()
+                .map(|item| Rc::new(RefCell::new(item)))
+                .collect(),
+        )
+    }
+}
+
+impl<T: std::fmt::Debug> FromIterator<Rc<RefCell<T>>> for LinkedList2<T> {
+    fn from_iter<I: IntoIterator<Item = Rc<RefCell<T>>>>(iter: I) -> Self {
+        LinkedList2::from_contents(iter.into_iter().collect())
+    }
+}
+
+impl<T: std::fmt::Debug> Extend<T> for LinkedList2<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.add_raw(item);
+        }
+    }
+}
+
+impl<T: std::fmt::Debug> Extend<Rc<RefCell<T>>> for LinkedList2<T> {
+    fn extend<I: IntoIterator<Item = Rc<RefCell<T>>>>(&mut self, iter: I) {
+        for item in iter {
+            self.add(item);
+        }
+    }
+}
+
+impl<T: std::fmt::Debug> From<Vec<T>> for LinkedList2<T> {
+    fn from(values: Vec<T>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T: std::fmt::Debug> From<Vec<Rc<RefCell<T>>>> for LinkedList2<T> {
+    fn from(contents: Vec<Rc<RefCell<T>>>) -> Self {
+        LinkedList2::from_contents(contents)
+    }
+}
+
+impl<T: Clone + std::fmt::Debug> From<&[T]> for LinkedList2<T> {
+    fn from(values: &[T]) -> Self {
+        values.iter().cloned().collect()
+    }
+}
+
+impl<T: std::fmt::Debug> From<LinkedList2<T>> for Vec<Rc<RefCell<T>>> {
+    fn from(list: LinkedList2<T>) -> Self {
+        list.iter().cloned().collect()
+    }
+}
+
+/// Elements that are still shared elsewhere are cloned out of their `RefCell`
+impl<T: Clone + std::fmt::Debug> From<LinkedList2<T>> for Vec<T> {
+    fn from(mut list: LinkedList2<T>) -> Self {
+        let mut values = Vec::with_capacity(list.size);
+        // unlinking node by node leaves the list holding no other reference to the contents
+        while let Ok(item) = list.shift() {
+            values.push(unwrap_or_clone(item));
+        }
+        values
+    }
+}
+
+/// `list[index]` walks to the element like `get`, panicking if `index` is out of bounds;
+/// see `set` to replace an element
+impl<T: std::fmt::Debug> Index<usize> for LinkedList2<T> {
+    type Output = RefCell<T>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.node_ref_at(index) {
+            Ok(node) => &ListNode2::peek(node).content,
+            Err(_) => panic!(
+                "index out of bounds: the size is {} but the index is {}",
+                self.size, index
+            ),
+        }
+    }
+}
+
+/// Serializes as a plain sequence of the elements
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize + std::fmt::Debug> serde::Serialize for LinkedList2<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(Some(self.size))?;
+        for content in self.iter() {
+            seq.serialize_element(&*content.borrow())?;
+        }
+        seq.end()
+    }
+}
+
+/// Deserializes from a plain sequence of the elements, building fresh nodes
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de> + std::fmt::Debug> serde::Deserialize<'de> for LinkedList2<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<T>::deserialize(deserializer).map(|items| items.into_iter().collect())
+    }
+}
+
+impl<T: std::fmt::Debug> Default for LinkedList2<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: std::fmt::Debug> Deque<T> for LinkedList2<T> {
+    fn push_front(&mut self, item: Rc<RefCell<T>>) {
+        LinkedList2::push_front(self, item);
+    }
+
+    fn push_back(&mut self, item: Rc<RefCell<T>>) {
+        LinkedList2::push_back(self, item);
+    }
+
+    fn pop_front(&mut self) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        self.shift()
+    }
+
+    fn pop_back(&mut self) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        self.pop()
+    }
+
+    fn front(&self) -> Option<Rc<RefCell<T>>> {
+        LinkedList2::front(self)
+    }
+
+    fn back(&self) -> Option<Rc<RefCell<T>>> {
+        LinkedList2::back(self)
+    }
+}
+
+impl<T: std::fmt::Debug> List<T> for LinkedList2<T> {
+    fn add(&mut self, item: Rc<RefCell<T>>) {
+        self.add_with_handle(item);
+    }
+
+    fn add_raw(&mut self, item: T) {
+        self.add(Rc::new(RefCell::new(item)));
+    }
+
+    fn insert_at(&mut self, item: Rc<RefCell<T>>, index: usize) -> Result<(), ListOperationErr> {
+        trace_op!(
+            list = "LinkedList2",
+            op = "insert_at",
+            index,
+            size = self.size
+        );
+        if index == self.size {
+            self.push_back(item);
+            return Ok(());
+        }
+        let next = self.get_node_at(index)?;
+        let prev = next.borrow().prev_node();
+
+        self.link_between(ListNode2::new(item), prev, Some(next));
+        Ok(())
+    }
+
+    fn insert_raw_at(&mut self, item: T, index: usize) -> Result<(), ListOperationErr> {
+        self.insert_at(Rc::new(RefCell::new(item)), index)
+    }
+
+    fn get(&self, index: usize) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        let node = self.node_ref_at(index)?;
+        Ok(ListNode2::peek(node).content.clone())
+    }
+
+    fn contains(&self, item: Rc<RefCell<T>>) -> bool {
+        self.index_with(&item, &EqStrategy::Identity).is_some()
+    }
+
+    fn remove(&mut self, item: Rc<RefCell<T>>) -> Result<(), ListOperationErr> {
+        trace_op!(list = "LinkedList2", op = "remove", size = self.size);
+        if self.is_empty() {
+            return Err(ListOperationErr::OperationOnEmptyList);
+        }
+
+        let index = self
+            .index_with(&item, &EqStrategy::Identity)
+            .ok_or(ListOperationErr::ElementNotFound)?;
+        let node = self.get_node_at(index)?;
+        self.remove_node(&node);
+        Ok(())
+    }
+
+    fn remove_at(&mut self, index: usize) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        trace_op!(
+            list = "LinkedList2",
+            op = "remove_at",
+            index,
+            size = self.size
+        );
+        let node = self.get_node_at(index)?;
+        Ok(self.remove_node(&node))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.size < 1
+    }
+
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn clear(&mut self) {
+        LinkedList2::clear(self);
+    }
+
+    fn first(&self) -> Option<Rc<RefCell<T>>> {
+        LinkedList2::front(self)
+    }
+
+    fn last(&self) -> Option<Rc<RefCell<T>>> {
+        LinkedList2::back(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list_of(items: std::ops::Range<i32>) -> LinkedList2<i32> {
+        items.map(|i| Rc::new(RefCell::new(i))).collect()
+    }
+
+    fn values(list: &LinkedList2<i32>) -> Vec<i32> {
+        list.to_vec()
+    }
+
+    /// Lengths covering empty, single element and longer lists
+    const LENGTHS: [usize; 3] = [0, 1, 6];
+
+    fn out_of_bounds(index: usize, len: usize) -> ListOperationErr {
+        ListOperationErr::IndexOutOfBounds { index, len }
+    }
+
+    #[test]
+    fn get_checks_every_index() {
+        for len in LENGTHS {
+            let list = list_of(0..len as i32);
+            for index in 0..len + 2 {
+                match list.get(index) {
+                    Ok(item) if index < len => assert_eq!(*item.borrow(), index as i32),
+                    result => assert_eq!(result.unwrap_err(), out_of_bounds(index, len)),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn insert_at_checks_every_index() {
+        for len in LENGTHS {
+            for index in 0..len + 2 {
+                let mut list = list_of(0..len as i32);
+                let mut model: Vec<i32> = (0..len as i32).collect();
+                let result = list.insert_at(Rc::new(RefCell::new(-1)), index);
+                if index <= len {
+                    result.unwrap();
+                    model.insert(index, -1);
+                } else {
+                    assert_eq!(result.unwrap_err(), out_of_bounds(index, len));
+                }
+                assert_eq!(values(&list), model);
+                list.check_invariants().unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn remove_at_checks_every_index() {
+        for len in LENGTHS {
+            for index in 0..len + 2 {
+                let mut list = list_of(0..len as i32);
+                let mut model: Vec<i32> = (0..len as i32).collect();
+                match list.remove_at(index) {
+                    Ok(item) if index < len => assert_eq!(*item.borrow(), model.remove(index)),
+                    result => assert_eq!(result.unwrap_err(), out_of_bounds(index, len)),
+                }
+                assert_eq!(values(&list), model);
+                list.check_invariants().unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn pop_and_shift_empty_the_list() {
+        for len in LENGTHS {
+            let mut popped = list_of(0..len as i32);
+            let mut shifted = list_of(0..len as i32);
+            for i in 0..len as i32 {
+                assert_eq!(*popped.pop().unwrap().borrow(), len as i32 - 1 - i);
+                assert_eq!(*shifted.shift().unwrap().borrow(), i);
+                popped.check_invariants().unwrap();
+                shifted.check_invariants().unwrap();
+            }
+            for list in [&mut popped, &mut shifted] {
+                assert_eq!(
+                    list.pop().unwrap_err(),
+                    ListOperationErr::OperationOnEmptyList
+                );
+                assert_eq!(
+                    list.shift().unwrap_err(),
+                    ListOperationErr::OperationOnEmptyList
+                );
+                assert!(list.is_empty());
+                list.check_invariants().unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn dropping_the_list_frees_every_element() {
+        let mut list = LinkedList2::new();
+        let mut elements = Vec::new();
+        let mut track = |value: i32| {
+            let item = Rc::new(RefCell::new(value));
+            elements.push(Rc::downgrade(&item));
+            item
+        };
+
+        for i in 0..4 {
+            list.add(track(i));
+        }
+        list.push_front(track(-1));
+        list.insert_at(track(10), 2).unwrap();
+        list.insert_at(track(11), 5).unwrap();
+        drop(list.remove_at(3).unwrap());
+        drop(list.remove_handle(&list.handle_at(1).unwrap()).unwrap());
+        list.check_invariants().unwrap();
+
+        drop(list);
+        assert!(elements.iter().all(|element| element.upgrade().is_none()));
+    }
+
+    #[test]
+    fn handles_of_another_list_are_rejected() {
+        let mut a = list_of(0..3);
+        let mut b = list_of(10..13);
+        let foreign = b.handle_at(1).unwrap();
+
+        let item = Rc::new(RefCell::new(99));
+        assert_eq!(
+            a.insert_after(&foreign, item.clone()).unwrap_err(),
+            ListOperationErr::ForeignHandle
+        );
+        assert_eq!(
+            a.insert_before(&foreign, item).unwrap_err(),
+            ListOperationErr::ForeignHandle
+        );
+        assert_eq!(
+            a.remove_handle(&foreign).unwrap_err(),
+            ListOperationErr::ForeignHandle
+        );
+
+        assert_eq!(values(&a), [0, 1, 2]);
+        assert_eq!(values(&b), [10, 11, 12]);
+        a.check_invariants().unwrap();
+        b.check_invariants().unwrap();
+        assert_eq!(*b.remove_handle(&foreign).unwrap().borrow(), 11);
+    }
+
+    #[test]
+    fn handles_follow_their_nodes_out_of_the_list() {
+        let mut a = list_of(0..4);
+        let moved = a.handle_at(3).unwrap();
+        let kept = a.handle_at(1).unwrap();
+        let mut tail = a.split_off(2).unwrap();
+        assert_eq!(*kept.content().unwrap().borrow(), 1);
+        assert_eq!(
+            a.remove_handle(&moved).unwrap_err(),
+            ListOperationErr::StaleHandle
+        );
+        assert_eq!(
+            tail.remove_handle(&moved).unwrap_err(),
+            ListOperationErr::ForeignHandle
+        );
+
+        let mut b = list_of(10..12);
+        let appended = b.handle_at(0).unwrap();
+        a.append(&mut b);
+        assert_eq!(
+            b.insert_after(&appended, Rc::new(RefCell::new(99)))
+                .unwrap_err(),
+            ListOperationErr::ForeignHandle
+        );
+        assert_eq!(
+            a.remove_handle(&appended).unwrap_err(),
+            ListOperationErr::ForeignHandle
+        );
+
+        // fresh handles work again
+        let handle = a.handle_at(2).unwrap();
+        assert_eq!(*a.remove_handle(&handle).unwrap().borrow(), 10);
+        assert_eq!(values(&a), [0, 1, 11]);
+        assert_eq!(values(&tail), [2, 3]);
+        a.check_invariants().unwrap();
+        tail.check_invariants().unwrap();
+    }
+
+    #[test]
+    fn snapshots_ignore_later_changes() {
+        let mut list = list_of(0..4);
+        let originals: Vec<_> = list.iter_snapshot().collect();
+        let mut snapshot = list.iter_snapshot();
+        assert_eq!(*snapshot.next_back().unwrap().borrow(), 3);
+
+        list.push_front(Rc::new(RefCell::new(-1)));
+        list.remove_at(1).unwrap();
+        assert_eq!(*snapshot.next().unwrap().borrow(), 0);
+        list.clear();
+        list.add_raw(5);
+
+        let rest: Vec<_> = snapshot.collect();
+        assert_eq!(rest.len(), 2);
+        assert!(rest
+            .iter()
+            .zip(&originals[1..3])
+            .all(|(a, b)| Rc::ptr_eq(a, b)));
+        assert_eq!(values(&list), [5]);
+    }
+
+    #[test]
+    fn handles_to_removed_nodes_are_stale() {
+        let stale = ListOperationErr::StaleHandle;
+        for index in 0..4 {
+            let mut list = list_of(0..4);
+            let handle = list.handle_at(index).unwrap();
+            let copy = handle.clone();
+            assert_eq!(*list.remove_handle(&handle).unwrap().borrow(), index as i32);
+
+            // new nodes never pass for the removed one, even at the same position
+            let fresh: Vec<_> = (0..4)
+                .map(|i| {
+                    let item = Rc::new(RefCell::new(10 + i));
+                    list.insert_at(item, index).unwrap();
+                    list.handle_at(index).unwrap()
+                })
+                .collect();
+            for old in [&handle, &copy] {
+                assert!(!old.is_valid());
+                assert!(fresh.iter().all(|new| new.id() != old.id()));
+                assert_eq!(old.content().unwrap_err(), stale);
+                assert!(old.next().is_none() && old.prev().is_none());
+                assert_eq!(
+                    list.insert_after(old, Rc::new(RefCell::new(-1)))
+                        .unwrap_err(),
+                    stale
+                );
+                assert_eq!(
+                    list.insert_before(old, Rc::new(RefCell::new(-1)))
+                        .unwrap_err(),
+                    stale
+                );
+                assert_eq!(list.move_handle_to_front(old).unwrap_err(), stale);
+                assert_eq!(list.remove_handle(old).unwrap_err(), stale);
+            }
+
+            let mut model: Vec<i32> = (0..4).filter(|&i| i != index as i32).collect();
+            model.splice(index..index, [13, 12, 11, 10]);
+            assert_eq!(values(&list), model);
+            list.check_invariants().unwrap();
+        }
+    }
+
+    #[test]
+    fn split_off_keeps_handles_to_retained_nodes() {
+        for len in LENGTHS {
+            for index in 0..=len {
+                let mut list = list_of(0..len as i32);
+                let handles: Vec<_> = list.handles().collect();
+                let mut rest = list.split_off(index).unwrap();
+
+                let (kept, moved) = if index == 0 {
+                    // everything moves with the list, handles follow
+                    (&[][..], &[][..])
+                } else {
+                    handles.split_at(index)
+                };
+                for (i, handle) in kept.iter().enumerate() {
+                    let inserted = list
+                        .insert_after(handle, Rc::new(RefCell::new(-1)))
+                        .unwrap();
+                    assert_eq!(*list.remove_handle(&inserted).unwrap().borrow(), -1);
+                    assert_eq!(*handle.content().unwrap().borrow(), i as i32);
+                }
+                for handle in moved {
+                    assert!(!handle.is_valid());
+                    assert_eq!(
+                        list.remove_handle(handle).unwrap_err(),
+                        ListOperationErr::StaleHandle
+                    );
+                    assert_eq!(
+                        rest.remove_handle(handle).unwrap_err(),
+                        ListOperationErr::ForeignHandle
+                    );
+                }
+                if index == 0 {
+                    // moving every node to the back in order keeps the order
+                    for handle in &handles {
+                        rest.move_handle_to_back(handle).unwrap();
+                    }
+                }
+                assert_eq!(values(&list), (0..index as i32).collect::<Vec<_>>());
+                assert_eq!(
+                    values(&rest),
+                    (index as i32..len as i32).collect::<Vec<_>>()
+                );
+                list.check_invariants().unwrap();
+                rest.check_invariants().unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn handles_survive_moving_the_list() {
+        let mut a = list_of(0..3);
+        let handle = a.handle_at(1).unwrap();
+        let mut b = mem::take(&mut a);
+        assert_eq!(
+            a.remove_handle(&handle).unwrap_err(),
+            ListOperationErr::ForeignHandle
+        );
+        assert_eq!(*b.remove_handle(&handle).unwrap().borrow(), 1);
+        assert_eq!(values(&b), [0, 2]);
+    }
+
+    #[test]
+    fn moving_a_handle_of_another_list_is_rejected() {
+        let mut a = list_of(0..3);
+        let mut b = list_of(10..13);
+        let foreign = b.handle_at(1).unwrap();
+        assert_eq!(
+            a.move_handle_to_front(&foreign).unwrap_err(),
+            ListOperationErr::ForeignHandle
+        );
+        assert_eq!(
+            a.move_handle_to_back(&foreign).unwrap_err(),
+            ListOperationErr::ForeignHandle
+        );
+        assert_eq!(values(&a), [0, 1, 2]);
+        a.check_invariants().unwrap();
+
+        b.move_handle_to_front(&foreign).unwrap();
+        assert_eq!(values(&b), [11, 10, 12]);
+        b.move_handle_to_back(&foreign).unwrap();
+        assert_eq!(values(&b), [10, 12, 11]);
+        b.check_invariants().unwrap();
+    }
+
+    /// Deterministic xorshift numbers, so failures replay
+    struct Rng(u64);
+
+    impl Rng {
+        fn below(&mut self, bound: usize) -> usize {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            (self.0 % bound as u64) as usize
+        }
+    }
+
+    #[test]
+    fn lookups_stay_right_across_relinking() {
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+        let mut list = list_of(0..16);
+        let mut model: Vec<i32> = (0..16).collect();
+        let mut next = 16;
+
+        for _ in 0..400 {
+            // a lookup first, so the position cache is set when the list is relinked
+            if !model.is_empty() {
+                let index = rng.below(model.len());
+                assert_eq!(*list.get(index).unwrap().borrow(), model[index]);
+            }
+            let len = model.len();
+            match rng.below(8) {
+                0 => {
+                    let index = rng.below(len + 1);
+                    list.insert_at(Rc::new(RefCell::new(next)), index).unwrap();
+                    model.insert(index, next);
+                    next += 1;
+                }
+                1 if len > 0 => {
+                    let index = rng.below(len);
+                    assert_eq!(
+                        *list.remove_at(index).unwrap().borrow(),
+                        model.remove(index)
+                    );
+                }
+                2 if len > 0 => {
+                    let handle = list.handle_at(rng.below(len)).unwrap();
+                    let index = model
+                        .iter()
+                        .position(|&v| v == *handle.content().unwrap().borrow());
+                    let index = index.unwrap();
+                    if rng.below(2) == 0 {
+                        list.insert_after(&handle, Rc::new(RefCell::new(next)))
+                            .unwrap();
+                        model.insert(index + 1, next);
+                        next += 1;
+                    } else {
+                        list.remove_handle(&handle).unwrap();
+                        model.remove(index);
+                    }
+                }
+                3 if len > 0 => {
+                    let index = rng.below(len);
+                    if rng.below(2) == 0 {
+                        list.move_to_front(index).unwrap();
+                        let value = model.remove(index);
+                        model.insert(0, value);
+                    } else {
+                        list.move_to_back(index).unwrap();
+                        let value = model.remove(index);
+                        model.push(value);
+                    }
+                }
+                4 => {
+                    let index = rng.below(len + 1);
+                    let mut tail = list.split_off(index).unwrap();
+                    let moved = model.split_off(index);
+                    assert_eq!(values(&tail), moved);
+                    tail.check_invariants().unwrap();
+                    // put the tail back in front, so the order keeps changing
+                    tail.append(&mut list);
+                    list = tail;
+                    model = moved.into_iter().chain(model).collect();
+                }
+                5 if len > 1 => {
+                    let (i, j) = (rng.below(len), rng.below(len));
+                    list.swap(i, j).unwrap();
+                    model.swap(i, j);
+                }
+                6 => {
+                    let mut cursor = list.cursor_mut();
+                    for _ in 0..rng.below(len + 1) {
+                        cursor.move_next();
+                    }
+                    let at = cursor.index().unwrap_or(len);
+                    let mut other = list_of(next..next + 2);
+                    cursor.splice_before(&mut other);
+                    model.splice(at..at, [next, next + 1]);
+                    next += 2;
+                }
+                _ => {
+                    if len > 0 && rng.below(2) == 0 {
+                        assert_eq!(*list.pop().unwrap().borrow(), model.pop().unwrap());
+                    } else if len > 0 {
+                        assert_eq!(*list.shift().unwrap().borrow(), model.remove(0));
+                    }
+                }
+            }
+            list.check_invariants().unwrap();
+            assert_eq!(list.size(), model.len());
+            for (index, value) in model.iter().enumerate().rev() {
+                assert_eq!(*list.get(index).unwrap().borrow(), *value);
+            }
+        }
     }
 }
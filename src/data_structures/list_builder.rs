@@ -0,0 +1,81 @@
+use super::linked_list::LinkedList;
+use super::linked_list2::LinkedList2;
+use std::{cell::RefCell, rc::Rc};
+
+/// ### Summary
+/// Accumulates elements and produces a finished `LinkedList`/`LinkedList2` in one shot.
+///
+/// Unlike calling `List::add` for every element, the list's `head`, `tail` and `size`
+/// are only set once, when the list is built.
+#[derive(Debug, Clone)]
+pub struct ListBuilder<T> {
+    contents: Vec<Rc<RefCell<T>>>,
+}
+
+impl<T> ListBuilder<T> {
+    /// Constructs an empty `ListBuilder<T>`
+    pub const fn new() -> Self {
+        ListBuilder {
+            contents: Vec::new(),
+        }
+    }
+
+    /// Constructs an empty `ListBuilder<T>` with room for `capacity` elements
+    /// #### Params
+    /// - `capacity` - the number of elements expected to be added
+    pub fn with_capacity(capacity: usize) -> Self {
+        ListBuilder {
+            contents: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// push an item to the end of the list being built
+    /// #### Params
+    /// - `item` - a reference to the item to add
+    pub fn push(mut self, item: Rc<RefCell<T>>) -> Self {
+        self.contents.push(item);
+        self
+    }
+
+    /// push an item to the end of the list being built
+    /// #### Params
+    /// - `item` - the item to add
+    pub fn push_raw(self, item: T) -> Self {
+        self.push(Rc::new(RefCell::new(item)))
+    }
+
+    /// push every item of `items` to the end of the list being built
+    /// #### Params
+    /// - `items` - the items to add
+    pub fn push_all<I: IntoIterator<Item = T>>(mut self, items: I) -> Self {
+        self.contents
+            .extend(items.into_iter().map(|item| Rc::new(RefCell::new(item))));
+        self
+    }
+
+    /// #### Returns
+    /// Number of elements pushed so far
+    pub fn size(&self) -> usize {
+        self.contents.len()
+    }
+
+    /// #### Returns
+    /// a `LinkedList<T>` holding the pushed elements in insertion order
+    pub fn build_linked_list(self) -> LinkedList<T> {
+        LinkedList::from_contents(self.contents)
+    }
+}
+
+impl<T: std::fmt::Debug> ListBuilder<T> {
+    /// #### Returns
+    /// a `LinkedList2<T>` holding the pushed elements in insertion order
+    pub fn build_linked_list2(self) -> LinkedList2<T> {
+        LinkedList2::from_contents(self.contents)
+    }
+}
+
+impl<T> Default for ListBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
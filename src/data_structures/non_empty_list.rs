@@ -0,0 +1,130 @@
+use super::linked_list::{LinkedList, List, ListOperationErr, SnapshotIterator};
+use super::linked_list2::LinkedList2;
+use std::{cell::RefCell, convert::TryFrom, mem, rc::Rc};
+
+/// ### Summary
+/// A list that always holds at least one element.
+///
+/// The last element is kept outside of the underlying `LinkedList2`, so `first()`,
+/// `last()` and `pop_into()` need no `OperationOnEmptyList` handling.
+#[derive(Clone)]
+pub struct NonEmptyList<T: std::fmt::Debug> {
+    init: LinkedList2<T>,
+    last: Rc<RefCell<T>>,
+}
+
+impl<T: std::fmt::Debug> NonEmptyList<T> {
+    /// Constructs a `NonEmptyList<T>` holding a single item
+    /// #### Params
+    /// - `item` - the item to hold
+    pub fn new(item: T) -> Self {
+        Self::from_rc(Rc::new(RefCell::new(item)))
+    }
+
+    /// Constructs a `NonEmptyList<T>` holding a single item
+    /// #### Params
+    /// - `item` - a reference to the item to hold
+    pub fn from_rc(item: Rc<RefCell<T>>) -> Self {
+        NonEmptyList {
+            init: LinkedList2::new(),
+            last: item,
+        }
+    }
+
+    /// add an item to the end of the list
+    /// #### Params
+    /// - `item` - a reference to the item to add
+    pub fn add(&mut self, item: Rc<RefCell<T>>) {
+        let last = mem::replace(&mut self.last, item);
+        self.init.add(last);
+    }
+
+    /// add an item to the end of the list
+    /// #### Params
+    /// - `item` - the item to add
+    pub fn add_raw(&mut self, item: T) {
+        self.add(Rc::new(RefCell::new(item)));
+    }
+
+    /// #### Returns
+    /// a reference to the first element
+    pub fn first(&self) -> Rc<RefCell<T>> {
+        self.init.front().unwrap_or_else(|| self.last.clone())
+    }
+
+    /// #### Returns
+    /// a reference to the last element
+    pub fn last(&self) -> Rc<RefCell<T>> {
+        self.last.clone()
+    }
+
+    /// get a reference to the item at the specified index
+    /// #### Params
+    /// - `index` - the index to lookup
+    pub fn get(&self, index: usize) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        if index == self.init.size() {
+            Ok(self.last.clone())
+        } else {
+            self.init.get(index)
+        }
+    }
+
+    /// Consumes the list, splitting off its last element
+    /// #### Returns
+    /// `(rest_of_list, last_element)`, where the rest may be empty
+    pub fn pop_into(self) -> (LinkedList2<T>, Rc<RefCell<T>>) {
+        (self.init, self.last)
+    }
+
+    /// #### Returns
+    /// Number of elements in list, always at least 1
+    pub fn size(&self) -> usize {
+        self.init.size() + 1
+    }
+
+    /// #### Returns
+    /// an iterator over the elements the list holds right now
+    pub fn iter_snapshot(&self) -> SnapshotIterator<T> {
+        let mut contents: Vec<_> = self.init.iter_snapshot().collect();
+        contents.push(self.last.clone());
+        SnapshotIterator::new(contents)
+    }
+
+    /// Consumes the list
+    /// #### Returns
+    /// a regular `LinkedList2<T>` with the same elements
+    pub fn into_list(self) -> LinkedList2<T> {
+        let (mut list, last) = self.pop_into();
+        list.add(last);
+        list
+    }
+}
+
+impl<T: std::fmt::Debug> TryFrom<LinkedList2<T>> for NonEmptyList<T> {
+    type Error = ListOperationErr;
+
+    fn try_from(mut list: LinkedList2<T>) -> Result<Self, Self::Error> {
+        let last = list.pop()?;
+        Ok(NonEmptyList { init: list, last })
+    }
+}
+
+impl<T: std::fmt::Debug> TryFrom<LinkedList<T>> for NonEmptyList<T> {
+    type Error = ListOperationErr;
+
+    fn try_from(list: LinkedList<T>) -> Result<Self, Self::Error> {
+        Self::try_from(LinkedList2::from_contents(list.into_iter().collect()))
+    }
+}
+
+impl<T: std::fmt::Debug> From<NonEmptyList<T>> for LinkedList2<T> {
+    fn from(list: NonEmptyList<T>) -> Self {
+        list.into_list()
+    }
+}
+
+impl<T: std::fmt::Debug> From<NonEmptyList<T>> for LinkedList<T> {
+    fn from(list: NonEmptyList<T>) -> Self {
+        LinkedList::from_contents(list.iter_snapshot().collect())
+    }
+}
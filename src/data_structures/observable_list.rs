@@ -0,0 +1,246 @@
+use super::linked_list::{List, ListOperationErr};
+use super::linked_list2::{LinkedList2, LinkedList2IntoIter, LinkedList2Iter};
+use std::{cell::RefCell, fmt, rc::Rc};
+
+/// A change to an `ObservableList`, passed to its listeners after it happened
+#[derive(Debug, Clone)]
+pub enum ListEvent<T> {
+    /// `item` was inserted at `index`; adding to the end inserts at the previous size
+    Inserted { index: usize, item: Rc<RefCell<T>> },
+    /// `item` was removed from `index`
+    Removed { index: usize, item: Rc<RefCell<T>> },
+    /// every element was removed at once
+    Cleared,
+}
+
+/// Identifies a listener registered with `ObservableList::subscribe`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ListenerId(usize);
+
+type Listener<T> = Box<dyn FnMut(&ListEvent<T>)>;
+
+/// ### Summary
+/// A `LinkedList2` that tells its listeners about every element added or removed.
+///
+/// Listeners are called in the order they subscribed, right after the list changed. Only
+/// the structure of the list is observed: changing an element through its `RefCell` fires
+/// nothing. The list is never handed out mutably, so every change goes through the events.
+pub struct ObservableList<T: std::fmt::Debug> {
+    list: LinkedList2<T>,
+    listeners: Vec<(ListenerId, Listener<T>)>,
+    next_id: usize,
+}
+
+impl<T: std::fmt::Debug> ObservableList<T> {
+    /// Constructs an empty `ObservableList<T>` without listeners
+    pub const fn new() -> Self {
+        ObservableList {
+            list: LinkedList2::new(),
+            listeners: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Registers `listener` to be called with every following change
+    /// #### Params
+    /// - `listener` - called with each event
+    /// #### Returns
+    /// the id to unsubscribe the listener with
+    pub fn subscribe<F: FnMut(&ListEvent<T>) + 'static>(&mut self, listener: F) -> ListenerId {
+        let id = ListenerId(self.next_id);
+        self.next_id += 1;
+        self.listeners.push((id, Box::new(listener)));
+        id
+    }
+
+    /// Stops calling the listener registered as `id`
+    /// #### Params
+    /// - `id` - the id `subscribe` returned
+    /// #### Returns
+    /// `true` if the listener was registered
+    pub fn unsubscribe(&mut self, id: ListenerId) -> bool {
+        let before = self.listeners.len();
+        self.listeners.retain(|(listener, _)| *listener != id);
+        self.listeners.len() != before
+    }
+
+    /// add an item to the front of the list
+    /// #### Params
+    /// - `item` - a reference to the item to add
+    pub fn push_front(&mut self, item: Rc<RefCell<T>>) {
+        self.list.push_front(item.clone());
+        self.notify(ListEvent::Inserted { index: 0, item });
+    }
+
+    /// Removes the first element of the list
+    pub fn shift(&mut self) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        let item = self.list.shift()?;
+        self.notify(ListEvent::Removed {
+            index: 0,
+            item: item.clone(),
+        });
+        Ok(item)
+    }
+
+    /// Removes the last element of the list
+    pub fn pop(&mut self) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        let item = self.list.pop()?;
+        self.notify(ListEvent::Removed {
+            index: self.list.size(),
+            item: item.clone(),
+        });
+        Ok(item)
+    }
+
+    /// #### Returns
+    /// an iterator over references to the elements, without consuming the list
+    pub fn iter(&self) -> LinkedList2Iter<'_, T> {
+        self.list.iter()
+    }
+
+    /// #### Returns
+    /// the observed list, to read it with any of its methods
+    pub fn as_list(&self) -> &LinkedList2<T> {
+        &self.list
+    }
+
+    /// Stops observing the list
+    /// #### Returns
+    /// the list, dropping the listeners
+    pub fn into_inner(self) -> LinkedList2<T> {
+        self.list
+    }
+
+    /// Calls every listener with `event`, in the order they subscribed
+    fn notify(&mut self, event: ListEvent<T>) {
+        for (_, listener) in &mut self.listeners {
+            listener(&event);
+        }
+    }
+}
+
+impl<T: std::fmt::Debug> Default for ObservableList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Observes `list` from now on, without listeners yet
+impl<T: std::fmt::Debug> From<LinkedList2<T>> for ObservableList<T> {
+    fn from(list: LinkedList2<T>) -> Self {
+        ObservableList {
+            list,
+            ..Self::new()
+        }
+    }
+}
+
+/// The clone shares the elements, but none of the listeners
+impl<T: std::fmt::Debug> Clone for ObservableList<T> {
+    fn clone(&self) -> Self {
+        Self::from(self.list.clone())
+    }
+}
+
+impl<T: std::fmt::Debug> fmt::Debug for ObservableList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ObservableList")
+            .field("list", &self.list)
+            .field("listeners", &self.listeners.len())
+            .finish()
+    }
+}
+
+impl<T: std::fmt::Debug> IntoIterator for ObservableList<T> {
+    type Item = Rc<RefCell<T>>;
+
+    type IntoIter = LinkedList2IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.into_iter()
+    }
+}
+
+impl<'a, T: std::fmt::Debug> IntoIterator for &'a ObservableList<T> {
+    type Item = &'a Rc<RefCell<T>>;
+
+    type IntoIter = LinkedList2Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: std::fmt::Debug> List<T> for ObservableList<T> {
+    fn add(&mut self, item: Rc<RefCell<T>>) {
+        let index = self.list.size();
+        self.list.add(item.clone());
+        self.notify(ListEvent::Inserted { index, item });
+    }
+
+    fn add_raw(&mut self, item: T) {
+        self.add(Rc::new(RefCell::new(item)));
+    }
+
+    fn insert_at(&mut self, item: Rc<RefCell<T>>, index: usize) -> Result<(), ListOperationErr> {
+        self.list.insert_at(item.clone(), index)?;
+        self.notify(ListEvent::Inserted { index, item });
+        Ok(())
+    }
+
+    fn insert_raw_at(&mut self, item: T, index: usize) -> Result<(), ListOperationErr> {
+        self.insert_at(Rc::new(RefCell::new(item)), index)
+    }
+
+    fn get(&self, index: usize) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        self.list.get(index)
+    }
+
+    fn remove(&mut self, item: Rc<RefCell<T>>) -> Result<(), ListOperationErr> {
+        if self.list.is_empty() {
+            return Err(ListOperationErr::OperationOnEmptyList);
+        }
+
+        let index = self
+            .list
+            .iter()
+            .position(|content| Rc::ptr_eq(content, &item))
+            .ok_or(ListOperationErr::ElementNotFound)?;
+        self.remove_at(index)?;
+        Ok(())
+    }
+
+    fn remove_at(&mut self, index: usize) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        let item = self.list.remove_at(index)?;
+        self.notify(ListEvent::Removed {
+            index,
+            item: item.clone(),
+        });
+        Ok(item)
+    }
+
+    fn contains(&self, item: Rc<RefCell<T>>) -> bool {
+        self.list.contains(item)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    fn size(&self) -> usize {
+        self.list.size()
+    }
+
+    fn clear(&mut self) {
+        self.list.clear();
+        self.notify(ListEvent::Cleared);
+    }
+
+    fn first(&self) -> Option<Rc<RefCell<T>>> {
+        self.list.front()
+    }
+
+    fn last(&self) -> Option<Rc<RefCell<T>>> {
+        self.list.back()
+    }
+}
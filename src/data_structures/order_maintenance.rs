@@ -0,0 +1,297 @@
+use super::linked_list::{List, ListOperationErr};
+use super::linked_list2::{LinkedList2, NodeHandle};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+/// Distance between the labels of nodes pushed at either end of the list
+const STRIDE: u64 = 1 << 32;
+
+/// ### Summary
+/// A `LinkedList2` whose node handles carry order labels, so
+/// `precedes(a, b)` is answered in O(1) instead of looking up both indices.
+///
+/// Labels are spread over the whole `u64` range; an insert takes the midpoint
+/// between its neighbours' labels and only relabels the list when no gap is left.
+/// Pushes at either end step a fixed `STRIDE` past the end label instead, so runs
+/// of them don't halve the gap every time.
+pub struct OrderMaintainedList<T: std::fmt::Debug> {
+    list: LinkedList2<T>,
+    labels: HashMap<usize, u64>,
+}
+
+impl<T: std::fmt::Debug> OrderMaintainedList<T> {
+    /// Constructs an empty `OrderMaintainedList<T>`
+    pub fn new() -> Self {
+        OrderMaintainedList {
+            list: LinkedList2::new(),
+            labels: HashMap::new(),
+        }
+    }
+
+    /// add an item to the end of the list
+    /// #### Params
+    /// - `item` - the item to add
+    /// #### Returns
+    /// a handle to the node holding `item`
+    pub fn push_back(&mut self, item: T) -> NodeHandle<T> {
+        let handle = self.list.add_with_handle(Rc::new(RefCell::new(item)));
+        self.label(&handle);
+        handle
+    }
+
+    /// add an item to the start of the list
+    /// #### Params
+    /// - `item` - the item to add
+    /// #### Returns
+    /// a handle to the node holding `item`
+    pub fn push_front(&mut self, item: T) -> NodeHandle<T> {
+        let handle = self
+            .list
+            .push_front_with_handle(Rc::new(RefCell::new(item)));
+        self.label(&handle);
+        handle
+    }
+
+    /// insert an item right after the node of `handle`
+    /// #### Params
+    /// - `handle` - a handle to a node of this list
+    /// - `item` - the item to insert
+    pub fn insert_after(
+        &mut self,
+        handle: &NodeHandle<T>,
+        item: T,
+    ) -> Result<NodeHandle<T>, ListOperationErr> {
+        self.label_of(handle)?;
+        let new_handle = self
+            .list
+            .insert_after(handle, Rc::new(RefCell::new(item)))?;
+        self.label(&new_handle);
+        Ok(new_handle)
+    }
+
+    /// insert an item right before the node of `handle`
+    /// #### Params
+    /// - `handle` - a handle to a node of this list
+    /// - `item` - the item to insert
+    pub fn insert_before(
+        &mut self,
+        handle: &NodeHandle<T>,
+        item: T,
+    ) -> Result<NodeHandle<T>, ListOperationErr> {
+        self.label_of(handle)?;
+        let new_handle = self
+            .list
+            .insert_before(handle, Rc::new(RefCell::new(item)))?;
+        self.label(&new_handle);
+        Ok(new_handle)
+    }
+
+    /// removes the node of `handle` from the list
+    /// #### Params
+    /// - `handle` - a handle to a node of this list
+    pub fn remove(&mut self, handle: &NodeHandle<T>) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        self.label_of(handle)?;
+        let result = self.list.remove_handle(handle)?;
+        self.labels.remove(&handle.id());
+        Ok(result)
+    }
+
+    /// checks whether the node of `a` comes before the node of `b`, in O(1)
+    /// #### Params
+    /// - `a`, `b` - handles to nodes of this list
+    pub fn precedes(&self, a: &NodeHandle<T>, b: &NodeHandle<T>) -> Result<bool, ListOperationErr> {
+        Ok(self.label_of(a)? < self.label_of(b)?)
+    }
+
+    /// #### Returns
+    /// the underlying list, for read-only traversal
+    pub fn list(&self) -> &LinkedList2<T> {
+        &self.list
+    }
+
+    /// #### Returns
+    /// `true` if the list is empty
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// #### Returns
+    /// Number of elements in list
+    pub fn size(&self) -> usize {
+        self.list.size()
+    }
+
+    /// Get the label of the node of `handle`
+    fn label_of(&self, handle: &NodeHandle<T>) -> Result<u64, ListOperationErr> {
+        if !handle.is_valid() {
+            return Err(ListOperationErr::StaleHandle);
+        }
+        self.labels
+            .get(&handle.id())
+            .copied()
+            .ok_or(ListOperationErr::ElementNotFound)
+    }
+
+    /// Labels a freshly linked node between its neighbours, relabeling everything if there is no gap
+    fn label(&mut self, handle: &NodeHandle<T>) {
+        let low = handle.prev().and_then(|prev| self.label_of(&prev).ok());
+        let high = handle.next().and_then(|next| self.label_of(&next).ok());
+
+        let label = match (low, high) {
+            (Some(low), None) => low.checked_add(STRIDE),
+            (None, Some(high)) => high.checked_sub(STRIDE),
+            _ => None,
+        }
+        .or_else(|| {
+            let (low, high) = (low.unwrap_or(0), high.unwrap_or(u64::MAX));
+            (high - low > 1).then(|| low + (high - low) / 2)
+        });
+        match label {
+            Some(label) => {
+                self.labels.insert(handle.id(), label);
+            }
+            None => self.relabel(),
+        }
+    }
+
+    /// Spreads the labels of all nodes evenly over the label range
+    fn relabel(&mut self) {
+        let gap = u64::MAX / (self.list.size() as u64 + 1);
+        self.labels.clear();
+        for (index, handle) in self.list.handles().enumerate() {
+            self.labels.insert(handle.id(), (index as u64 + 1) * gap);
+        }
+    }
+}
+
+impl<T: std::fmt::Debug> Default for OrderMaintainedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks the labels and `precedes` against the order of `expected`, which must
+    /// hold handles to every node of `list` in list order
+    fn assert_order(list: &OrderMaintainedList<i32>, expected: &[NodeHandle<i32>]) {
+        let ids: Vec<usize> = list.list().handles().map(|handle| handle.id()).collect();
+        let expected_ids: Vec<usize> = expected.iter().map(|handle| handle.id()).collect();
+        assert_eq!(ids, expected_ids);
+
+        let labels: Vec<u64> = expected
+            .iter()
+            .map(|handle| list.label_of(handle).unwrap())
+            .collect();
+        assert!(
+            labels.windows(2).all(|pair| pair[0] < pair[1]),
+            "labels out of order: {:?}",
+            labels
+        );
+        // `precedes` compares labels, so neighbours in both directions cover every pair
+        for (i, pair) in expected.windows(2).enumerate() {
+            assert_eq!(list.precedes(&pair[0], &pair[1]), Ok(true), "at {}", i);
+            assert_eq!(list.precedes(&pair[1], &pair[0]), Ok(false), "at {}", i);
+            assert_eq!(list.precedes(&pair[0], &pair[0]), Ok(false), "at {}", i);
+        }
+        if let (Some(first), Some(last)) = (expected.first(), expected.last()) {
+            assert_eq!(list.precedes(first, last), Ok(expected.len() > 1));
+        }
+    }
+
+    #[test]
+    fn precedes_follows_interleaved_inserts() {
+        let mut list = OrderMaintainedList::new();
+        let mut expected = vec![list.push_back(0)];
+        for i in 1..40 {
+            let at = (i * 7) % expected.len();
+            match i % 4 {
+                0 => {
+                    let handle = list.insert_after(&expected[at], i as i32).unwrap();
+                    expected.insert(at + 1, handle);
+                }
+                1 => {
+                    let handle = list.insert_before(&expected[at], i as i32).unwrap();
+                    expected.insert(at, handle);
+                }
+                2 => expected.insert(0, list.push_front(i as i32)),
+                _ => expected.push(list.push_back(i as i32)),
+            }
+            assert_order(&list, &expected);
+        }
+        assert_eq!(list.size(), expected.len());
+    }
+
+    #[test]
+    fn repeated_inserts_at_one_spot_relabel() {
+        let mut list = OrderMaintainedList::new();
+        let first = list.push_back(0);
+        let mut expected = vec![first.clone(), list.push_back(1)];
+        let first_label = list.label_of(&first).unwrap();
+
+        // every insert halves the gap after `first`, so this runs out of labels
+        for i in 2..100 {
+            expected.insert(1, list.insert_after(&first, i).unwrap());
+        }
+        assert_ne!(list.label_of(&first), Ok(first_label));
+        assert_order(&list, &expected);
+    }
+
+    #[test]
+    fn pushes_at_the_ends_do_not_relabel() {
+        let mut list = OrderMaintainedList::new();
+        let first = list.push_back(0);
+        let first_label = list.label_of(&first).unwrap();
+        let mut expected = vec![first.clone()];
+        // halving the gap past either end would run out of labels well before this
+        for i in 1..200 {
+            if i % 2 == 0 {
+                expected.push(list.push_back(i));
+            } else {
+                expected.insert(0, list.push_front(i));
+            }
+        }
+        assert_eq!(list.label_of(&first), Ok(first_label));
+        assert_order(&list, &expected);
+    }
+
+    #[test]
+    fn precedes_follows_removals() {
+        let mut list = OrderMaintainedList::new();
+        let mut expected: Vec<_> = (0..10).map(|i| list.push_back(i)).collect();
+        for at in [9, 0, 4, 2] {
+            let handle = expected.remove(at);
+            assert!(list.remove(&handle).is_ok());
+            assert_order(&list, &expected);
+        }
+        let handle = list.insert_after(&expected[1], 10).unwrap();
+        expected.insert(2, handle);
+        assert_order(&list, &expected);
+    }
+
+    #[test]
+    fn stale_and_foreign_handles_are_rejected() {
+        let mut list = OrderMaintainedList::new();
+        let a = list.push_back(0);
+        let b = list.push_back(1);
+        assert!(list.remove(&a).is_ok());
+
+        let stale = ListOperationErr::StaleHandle;
+        assert_eq!(list.precedes(&a, &b), Err(stale.clone()));
+        assert_eq!(list.precedes(&b, &a), Err(stale.clone()));
+        assert_eq!(list.insert_after(&a, 2).unwrap_err(), stale);
+        assert_eq!(list.insert_before(&a, 2).unwrap_err(), stale);
+        assert_eq!(list.remove(&a).unwrap_err(), stale);
+
+        let mut other = OrderMaintainedList::new();
+        let foreign = other.push_back(0);
+        let not_found = ListOperationErr::ElementNotFound;
+        assert_eq!(list.precedes(&foreign, &b), Err(not_found.clone()));
+        assert_eq!(list.insert_after(&foreign, 2).unwrap_err(), not_found);
+        assert_eq!(list.insert_before(&foreign, 2).unwrap_err(), not_found);
+        assert_eq!(list.remove(&foreign).unwrap_err(), not_found);
+        assert_order(&list, &[b]);
+        assert_eq!(other.size(), 1);
+    }
+}
@@ -0,0 +1,406 @@
+use std::{fmt, iter::FromIterator, marker::PhantomData, ptr::NonNull};
+
+/// A link to the next node, owned by the node (or list) holding it
+type OwnedLink<T> = Option<NonNull<Node<T>>>;
+
+struct Node<T> {
+    item: T,
+    next: OwnedLink<T>,
+}
+
+impl<T> Node<T> {
+    /// Boxes a new node with no linked node
+    /// ### Returns
+    /// a pointer owning the newly created node
+    fn new(item: T) -> NonNull<Node<T>> {
+        NonNull::from(Box::leak(Box::new(Node { item, next: None })))
+    }
+}
+
+/// ### Summary
+/// A singly linked list owning its elements directly, without `Rc<RefCell<_>>`.
+///
+/// Every node is a `Box` owned by the node before it (the head by the list). The links are
+/// kept as raw pointers, so the list can also point at its tail and `push_back` is O(1).
+/// There is no reference count or borrow flag to update on access, and `iter`/`iter_mut`
+/// hand out plain `&T`/`&mut T`. The price is that elements cannot be shared with other
+/// lists, so it does not implement `List`.
+pub struct OwnedLinkedList<T> {
+    head: OwnedLink<T>,
+    /// the last node, owned through the chain starting at `head`
+    tail: OwnedLink<T>,
+    size: usize,
+    marker: PhantomData<Box<Node<T>>>,
+}
+
+// SAFETY: the list owns its nodes and their elements exclusively, like a `Vec<T>` would,
+// the raw pointers are never shared outside of borrows of the list.
+unsafe impl<T: Send> Send for OwnedLinkedList<T> {}
+// SAFETY: `&OwnedLinkedList<T>` only hands out `&T`.
+unsafe impl<T: Sync> Sync for OwnedLinkedList<T> {}
+
+impl<T> OwnedLinkedList<T> {
+    /// Constructs an empty `OwnedLinkedList<T>`
+    pub const fn new() -> Self {
+        OwnedLinkedList {
+            head: None,
+            tail: None,
+            size: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// add an item to the end of the list
+    /// #### Params
+    /// - `item` - the item to add
+    pub fn push_back(&mut self, item: T) {
+        trace_op!(list = "OwnedLinkedList", op = "push_back", size = self.size);
+        touch_nodes!(2);
+        let node = Node::new(item);
+        match self.tail {
+            // SAFETY: `tail` points to the last node, which the list owns
+            Some(mut tail) => unsafe { tail.as_mut().next = Some(node) },
+            None => self.head = Some(node),
+        }
+        self.tail = Some(node);
+        self.size += 1;
+    }
+
+    /// add an item to the front of the list
+    /// #### Params
+    /// - `item` - the item to add
+    pub fn push_front(&mut self, item: T) {
+        trace_op!(
+            list = "OwnedLinkedList",
+            op = "push_front",
+            size = self.size
+        );
+        touch_nodes!(1);
+        let mut node = Node::new(item);
+        // SAFETY: `node` was just allocated and is not linked anywhere yet
+        unsafe { node.as_mut().next = self.head };
+        if self.tail.is_none() {
+            self.tail = Some(node);
+        }
+        self.head = Some(node);
+        self.size += 1;
+    }
+
+    /// Removes the first element of the list
+    /// #### Returns
+    /// the removed element, `None` if the list is empty
+    pub fn pop_front(&mut self) -> Option<T> {
+        trace_op!(list = "OwnedLinkedList", op = "pop_front", size = self.size);
+        touch_nodes!(1);
+        let head = self.head?;
+        // SAFETY: `head` was leaked from a `Box` by `Node::new` and is unlinked right here,
+        // so ownership goes back to exactly one `Box`
+        let node = unsafe { Box::from_raw(head.as_ptr()) };
+        self.head = node.next;
+        if self.head.is_none() {
+            self.tail = None;
+        }
+        self.size -= 1;
+        Some(node.item)
+    }
+
+    /// #### Returns
+    /// a reference to the first element
+    pub fn front(&self) -> Option<&T> {
+        // SAFETY: the node is owned by the list, which is borrowed for the returned lifetime
+        self.head.map(|head| unsafe { &(*head.as_ptr()).item })
+    }
+
+    /// #### Returns
+    /// a mutable reference to the first element
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: the node is owned by the list, which is borrowed mutably for the returned lifetime
+        self.head.map(|head| unsafe { &mut (*head.as_ptr()).item })
+    }
+
+    /// #### Returns
+    /// a reference to the last element, without walking the list
+    pub fn back(&self) -> Option<&T> {
+        // SAFETY: see `front`
+        self.tail.map(|tail| unsafe { &(*tail.as_ptr()).item })
+    }
+
+    /// #### Returns
+    /// a mutable reference to the last element, without walking the list
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: see `front_mut`
+        self.tail.map(|tail| unsafe { &mut (*tail.as_ptr()).item })
+    }
+
+    /// #### Returns
+    /// an iterator over references to the elements, from the head
+    pub fn iter(&self) -> OwnedIter<'_, T> {
+        OwnedIter {
+            next: self.head,
+            remaining: self.size,
+            marker: PhantomData,
+        }
+    }
+
+    /// #### Returns
+    /// an iterator over mutable references to the elements, from the head
+    pub fn iter_mut(&mut self) -> OwnedIterMut<'_, T> {
+        OwnedIterMut {
+            next: self.head,
+            remaining: self.size,
+            marker: PhantomData,
+        }
+    }
+
+    /// Removes every element, freeing the nodes one by one
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    /// #### Returns
+    /// `true` if the list is empty
+    pub fn is_empty(&self) -> bool {
+        self.size < 1
+    }
+
+    /// #### Returns
+    /// Number of elements in list
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+impl<T> Default for OwnedLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for OwnedLinkedList<T> {
+    fn drop(&mut self) {
+        // the nodes are not freed by anything else
+        self.clear();
+    }
+}
+
+impl<T: Clone> Clone for OwnedLinkedList<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for OwnedLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> FromIterator<T> for OwnedLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = OwnedLinkedList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> Extend<T> for OwnedLinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push_back(item);
+        }
+    }
+}
+
+/// Iterator over references to the elements of an `OwnedLinkedList`, see `OwnedLinkedList::iter`
+pub struct OwnedIter<'a, T> {
+    next: OwnedLink<T>,
+    remaining: usize,
+    marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for OwnedIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY: the list is borrowed shared for `'a`, so its nodes are alive and unchanged
+        let node = unsafe { &*self.next?.as_ptr() };
+        self.next = node.next;
+        self.remaining -= 1;
+        Some(&node.item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for OwnedIter<'a, T> {}
+
+/// Iterator over mutable references to the elements of an `OwnedLinkedList`,
+/// see `OwnedLinkedList::iter_mut`
+pub struct OwnedIterMut<'a, T> {
+    next: OwnedLink<T>,
+    remaining: usize,
+    marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for OwnedIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY: the list is borrowed mutably for `'a` and every node is yielded once,
+        // so the returned references never alias
+        let node = unsafe { &mut *self.next?.as_ptr() };
+        self.next = node.next;
+        self.remaining -= 1;
+        Some(&mut node.item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for OwnedIterMut<'a, T> {}
+
+/// Iterator moving the elements out of an `OwnedLinkedList`
+pub struct OwnedIntoIter<T> {
+    list: OwnedLinkedList<T>,
+}
+
+impl<T> Iterator for OwnedIntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.size, Some(self.list.size))
+    }
+}
+
+impl<T> ExactSizeIterator for OwnedIntoIter<T> {}
+
+impl<T> IntoIterator for OwnedLinkedList<T> {
+    type Item = T;
+
+    type IntoIter = OwnedIntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        OwnedIntoIter { list: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a OwnedLinkedList<T> {
+    type Item = &'a T;
+
+    type IntoIter = OwnedIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut OwnedLinkedList<T> {
+    type Item = &'a mut T;
+
+    type IntoIter = OwnedIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_structures::linked_list::{LinkedList, List};
+    use std::{cell::RefCell, rc::Rc};
+
+    /// Deterministic xorshift numbers, so failures replay
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+    }
+
+    fn shared_values(list: &LinkedList<u64>) -> Vec<u64> {
+        list.iter().map(|item| *item.borrow()).collect()
+    }
+
+    /// Runs the same random mix of pushes at both ends and pops on both lists
+    fn filled_alike(ops: usize) -> (OwnedLinkedList<u64>, LinkedList<u64>) {
+        let mut rng = Rng(0x5eed);
+        let mut owned = OwnedLinkedList::new();
+        let mut shared = LinkedList::new();
+        for _ in 0..ops {
+            let value = rng.next();
+            match value % 4 {
+                0 => assert_eq!(
+                    owned.pop_front(),
+                    shared.shift().ok().map(|item| *item.borrow())
+                ),
+                1 => {
+                    owned.push_front(value);
+                    shared.push_front(Rc::new(RefCell::new(value)));
+                }
+                _ => {
+                    owned.push_back(value);
+                    shared.push_back(Rc::new(RefCell::new(value)));
+                }
+            }
+            assert_eq!(owned.size(), shared.size());
+            assert_eq!(
+                owned.front(),
+                shared.front().map(|item| *item.borrow()).as_ref()
+            );
+            assert_eq!(
+                owned.back(),
+                shared.back().map(|item| *item.borrow()).as_ref()
+            );
+        }
+        (owned, shared)
+    }
+
+    #[test]
+    fn pushes_and_pops_match_linked_list() {
+        let (owned, shared) = filled_alike(1_000);
+        assert!(owned.iter().copied().eq(shared_values(&shared)));
+    }
+
+    #[test]
+    fn popping_everything_matches_linked_list() {
+        let (mut owned, mut shared) = filled_alike(300);
+        while let Ok(item) = shared.shift() {
+            assert_eq!(owned.pop_front(), Some(*item.borrow()));
+        }
+        assert_eq!(owned.pop_front(), None);
+        assert!(owned.is_empty());
+    }
+
+    #[test]
+    fn iter_mut_matches_linked_list() {
+        let (mut owned, mut shared) = filled_alike(300);
+        for item in owned.iter_mut() {
+            *item = item.wrapping_mul(3);
+        }
+        for mut item in shared.iter_mut() {
+            *item = item.wrapping_mul(3);
+        }
+        assert!(owned.iter().copied().eq(shared_values(&shared)));
+    }
+
+    #[test]
+    fn into_iter_matches_linked_list() {
+        let (owned, shared) = filled_alike(300);
+        let expected: Vec<u64> = shared.into_iter().map(|item| *item.borrow()).collect();
+        assert_eq!(owned.iter().len(), expected.len());
+        assert_eq!(owned.into_iter().collect::<Vec<_>>(), expected);
+    }
+}
@@ -0,0 +1,139 @@
+use super::linked_list::{List, ListOperationErr, SnapshotIterator};
+use super::linked_list2::LinkedList2;
+use super::stack::Stack;
+use std::{cell::RefCell, rc::Rc};
+
+/// ### Summary
+/// Represents a first-in, first-out queue of items of type `T`
+pub trait FifoQueue<T> {
+    /// add an item to the back of the queue
+    /// #### Params
+    /// - `item` - the item to add
+    fn enqueue(&mut self, item: T);
+
+    /// Removes the item at the front of the queue
+    fn dequeue(&mut self) -> Result<Rc<RefCell<T>>, ListOperationErr>;
+
+    /// #### Returns
+    /// a reference to the item at the front of the queue
+    fn peek_front(&self) -> Result<Rc<RefCell<T>>, ListOperationErr>;
+
+    /// #### Returns
+    /// Number of items in the queue
+    fn len(&self) -> usize;
+
+    /// #### Returns
+    /// `true` if the queue is empty
+    fn is_empty(&self) -> bool {
+        self.len() < 1
+    }
+}
+
+/// ### Summary
+/// A queue backed by a `LinkedList2`: items are added at the tail and removed at the head,
+/// so `enqueue`, `dequeue` and `peek_front` are O(1).
+#[derive(Clone, Debug)]
+pub struct Queue<T: std::fmt::Debug> {
+    list: LinkedList2<T>,
+}
+
+impl<T: std::fmt::Debug> Queue<T> {
+    /// Constructs an empty `Queue<T>`
+    pub const fn new() -> Self {
+        Queue {
+            list: LinkedList2::new(),
+        }
+    }
+
+    /// #### Returns
+    /// an iterator over the items in the queue, from front to back
+    pub fn iter_snapshot(&self) -> SnapshotIterator<T> {
+        self.list.iter_snapshot()
+    }
+
+    /// Consumes the queue
+    /// #### Returns
+    /// the backing list, with the front of the queue at its head
+    pub fn into_inner(self) -> LinkedList2<T> {
+        self.list
+    }
+}
+
+impl<T: std::fmt::Debug> Default for Queue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: std::fmt::Debug> FifoQueue<T> for Queue<T> {
+    fn enqueue(&mut self, item: T) {
+        self.list.add_raw(item);
+    }
+
+    fn dequeue(&mut self) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        self.list.shift()
+    }
+
+    fn peek_front(&self) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        self.list
+            .front()
+            .ok_or(ListOperationErr::OperationOnEmptyList)
+    }
+
+    fn len(&self) -> usize {
+        self.list.size()
+    }
+}
+
+/// ### Summary
+/// A queue built from two LIFO stacks: items are pushed onto `inbox` and,
+/// once `outbox` runs dry, moved over in one go so they come out in FIFO order.
+///
+/// Every item is moved at most once, so `enqueue`/`dequeue` are amortized O(1).
+#[derive(Clone)]
+pub struct TwoStackQueue<T> {
+    inbox: Stack<T>,
+    outbox: Stack<T>,
+}
+
+impl<T> TwoStackQueue<T> {
+    /// Constructs an empty `TwoStackQueue<T>`
+    pub const fn new() -> Self {
+        TwoStackQueue {
+            inbox: Stack::new(),
+            outbox: Stack::new(),
+        }
+    }
+}
+
+impl<T> Default for TwoStackQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FifoQueue<T> for TwoStackQueue<T> {
+    fn enqueue(&mut self, item: T) {
+        self.inbox.push_raw(item);
+    }
+
+    fn dequeue(&mut self) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        if self.outbox.is_empty() {
+            // reverse the inbox into the outbox
+            while let Ok(item) = self.inbox.pop() {
+                self.outbox.push(item);
+            }
+        }
+
+        self.outbox.pop()
+    }
+
+    fn peek_front(&self) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        // the front is on top of the outbox, or at the bottom of the inbox
+        self.outbox.peek().or_else(|_| self.inbox.bottom())
+    }
+
+    fn len(&self) -> usize {
+        self.inbox.len() + self.outbox.len()
+    }
+}
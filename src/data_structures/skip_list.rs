@@ -0,0 +1,446 @@
+use super::linked_list::{fmt_elements, List, ListOperationErr, SnapshotIterator};
+use std::{cell::RefCell, fmt, rc::Rc};
+
+/// Number of levels of the head tower, enough for 2^32 elements
+const MAX_LEVEL: usize = 32;
+
+type SkipLink<T> = Option<Rc<RefCell<SkipNode<T>>>>;
+
+struct SkipNode<T> {
+    /// `None` only for the head sentinel
+    content: Option<Rc<RefCell<T>>>,
+    /// the next node on every level of this node's tower
+    forward: Vec<SkipLink<T>>,
+    /// how many positions each `forward` link skips; a missing link skips to one past the end
+    width: Vec<usize>,
+}
+
+impl<T> SkipNode<T> {
+    /// Creates a new node with a tower of `height` unlinked levels
+    /// ### Returns
+    /// a reference to the newly created node
+    fn new(content: Option<Rc<RefCell<T>>>, height: usize) -> Rc<RefCell<SkipNode<T>>> {
+        Rc::new(RefCell::new(SkipNode {
+            content,
+            forward: vec![None; height],
+            width: vec![1; height],
+        }))
+    }
+}
+
+/// ### Summary
+/// A list with O(log n) expected `get`, `insert_at` and `remove_at` by index.
+///
+/// Every node carries a tower of forward links of random height, and every link records
+/// how many elements it skips, so an index lookup descends the towers instead of walking
+/// every node. Tower heights come from a fixed-seed generator, so runs are reproducible.
+pub struct SkipList<T> {
+    /// sentinel before the first element, with a full-height tower
+    head: Rc<RefCell<SkipNode<T>>>,
+    size: usize,
+    rng: u64,
+}
+
+impl<T> SkipList<T> {
+    /// Constructs an empty `SkipList<T>`
+    pub fn new() -> Self {
+        SkipList {
+            head: SkipNode::new(None, MAX_LEVEL),
+            size: 0,
+            rng: 0x2545_f491_4f6c_dd1d,
+        }
+    }
+
+    /// Check index bounds
+    pub fn index_check(&self, index: usize) -> Result<(), ListOperationErr> {
+        if self.size <= index {
+            Err(ListOperationErr::IndexOutOfBounds {
+                index,
+                len: self.size,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Removes the first element of the list
+    pub fn shift(&mut self) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        if self.is_empty() {
+            return Err(ListOperationErr::OperationOnEmptyList);
+        }
+        self.remove_at(0)
+    }
+
+    /// Removes the last element of the list
+    pub fn pop(&mut self) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        let last = self
+            .size
+            .checked_sub(1)
+            .ok_or(ListOperationErr::OperationOnEmptyList)?;
+        self.remove_at(last)
+    }
+
+    /// #### Returns
+    /// an iterator over the elements the list holds right now
+    pub fn iter_snapshot(&self) -> SnapshotIterator<T> {
+        SnapshotIterator::new(self.contents())
+    }
+
+    /// Removes every element, unlinking the nodes one by one
+    pub fn clear(&mut self) {
+        let mut head = self.head.borrow_mut();
+        let head = &mut *head;
+        let mut cur = head.forward[0].take();
+        for (link, width) in head.forward.iter_mut().zip(head.width.iter_mut()) {
+            *link = None;
+            *width = 1;
+        }
+        while let Some(node) = cur {
+            let mut node = node.borrow_mut();
+            cur = node.forward[0].take();
+            node.forward.clear();
+        }
+        self.size = 0;
+    }
+
+    /// Collects the elements in order along the bottom level
+    fn contents(&self) -> Vec<Rc<RefCell<T>>> {
+        let mut contents = Vec::with_capacity(self.size);
+        let mut cur = self.head.borrow().forward[0].clone();
+        while let Some(c) = cur {
+            let node = c.borrow();
+            if let Some(content) = &node.content {
+                contents.push(content.clone());
+            }
+            cur = node.forward[0].clone();
+        }
+        contents
+    }
+
+    /// Descends the towers towards position `pos`, where the first element is at position 1
+    /// #### Returns
+    /// for every level, the last node before `pos` and that node's position
+    fn predecessors(&self, pos: usize) -> Vec<(Rc<RefCell<SkipNode<T>>>, usize)> {
+        let mut preds = Vec::with_capacity(MAX_LEVEL);
+        let mut node = self.head.clone();
+        let mut node_pos = 0;
+        for level in (0..MAX_LEVEL).rev() {
+            loop {
+                let next = match &node.borrow().forward[level] {
+                    Some(next) if node_pos + node.borrow().width[level] < pos => {
+                        Some((next.clone(), node.borrow().width[level]))
+                    }
+                    _ => None,
+                };
+                match next {
+                    Some((next, width)) => {
+                        node = next;
+                        node_pos += width;
+                        touch_nodes!(1);
+                    }
+                    None => break,
+                }
+            }
+            preds.push((node.clone(), node_pos));
+        }
+        preds.reverse();
+        preds
+    }
+
+    /// Picks a tower height, each level above the first with probability 1/2
+    fn random_height(&mut self) -> usize {
+        // xorshift64
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+        (1 + self.rng.trailing_ones() as usize).min(MAX_LEVEL)
+    }
+
+    /// Links `item` in at `index`, which may be one past the last element
+    fn insert(&mut self, item: Rc<RefCell<T>>, index: usize) {
+        let preds = self.predecessors(index + 1);
+        let height = self.random_height();
+        let node = SkipNode::new(Some(item), height);
+
+        touch_nodes!(height);
+        for (level, (pred, pred_pos)) in preds.iter().enumerate() {
+            let mut pred = pred.borrow_mut();
+            if level < height {
+                let mut new = node.borrow_mut();
+                new.forward[level] = pred.forward[level].replace(node.clone());
+                new.width[level] = pred_pos + pred.width[level] - index;
+                pred.width[level] = index + 1 - pred_pos;
+            } else {
+                pred.width[level] += 1;
+            }
+        }
+        self.size += 1;
+    }
+}
+
+impl<T> Default for SkipList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for SkipList<T> {
+    fn drop(&mut self) {
+        // unlink iteratively, dropping the bottom level recursively could overflow the stack
+        self.clear();
+    }
+}
+
+impl<T> Clone for SkipList<T> {
+    fn clone(&self) -> Self {
+        let mut clone = SkipList::new();
+        for content in self.contents() {
+            clone.add(content);
+        }
+        clone
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SkipList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_elements(f, self.size, self.contents().iter(), T::fmt)
+    }
+}
+
+impl<T> IntoIterator for SkipList<T> {
+    type Item = Rc<RefCell<T>>;
+
+    type IntoIter = SnapshotIterator<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_snapshot()
+    }
+}
+
+impl<T> List<T> for SkipList<T> {
+    fn add(&mut self, item: Rc<RefCell<T>>) {
+        trace_op!(list = "SkipList", op = "add", size = self.size);
+        self.insert(item, self.size);
+    }
+
+    fn add_raw(&mut self, item: T) {
+        self.add(Rc::new(RefCell::new(item)));
+    }
+
+    fn insert_at(&mut self, item: Rc<RefCell<T>>, index: usize) -> Result<(), ListOperationErr> {
+        trace_op!(list = "SkipList", op = "insert_at", index, size = self.size);
+        if index > self.size {
+            return Err(ListOperationErr::IndexOutOfBounds {
+                index,
+                len: self.size,
+            });
+        }
+        self.insert(item, index);
+        Ok(())
+    }
+
+    fn insert_raw_at(&mut self, item: T, index: usize) -> Result<(), ListOperationErr> {
+        self.insert_at(Rc::new(RefCell::new(item)), index)
+    }
+
+    fn get(&self, index: usize) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        self.index_check(index)?;
+        let (pred, _) = self.predecessors(index + 1).swap_remove(0);
+        let Some(node) = pred.borrow().forward[0].clone() else {
+            unreachable!("the predecessor of a valid index links to its node");
+        };
+        let Some(content) = node.borrow().content.clone() else {
+            unreachable!("only the head node has no content");
+        };
+        Ok(content)
+    }
+
+    fn remove(&mut self, item: Rc<RefCell<T>>) -> Result<(), ListOperationErr> {
+        trace_op!(list = "SkipList", op = "remove", size = self.size);
+        if self.is_empty() {
+            return Err(ListOperationErr::OperationOnEmptyList);
+        }
+
+        let index = self
+            .contents()
+            .iter()
+            .position(|content| Rc::ptr_eq(content, &item))
+            .ok_or(ListOperationErr::ElementNotFound)?;
+        self.remove_at(index)?;
+        Ok(())
+    }
+
+    fn remove_at(&mut self, index: usize) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        trace_op!(list = "SkipList", op = "remove_at", index, size = self.size);
+        self.index_check(index)?;
+        let preds = self.predecessors(index + 1);
+        let Some(target) = preds[0].0.borrow().forward[0].clone() else {
+            unreachable!("the predecessor of a valid index links to its node");
+        };
+
+        let mut removed = target.borrow_mut();
+        touch_nodes!(removed.forward.len());
+        for (level, (pred, _)) in preds.iter().enumerate() {
+            let mut pred = pred.borrow_mut();
+            let links_target =
+                matches!(&pred.forward[level], Some(next) if Rc::ptr_eq(next, &target));
+            if links_target {
+                pred.forward[level] = removed.forward[level].take();
+                pred.width[level] += removed.width[level] - 1;
+            } else {
+                pred.width[level] -= 1;
+            }
+        }
+        self.size -= 1;
+
+        let Some(content) = removed.content.take() else {
+            unreachable!("only the head node has no content");
+        };
+        Ok(content)
+    }
+
+    fn contains(&self, item: Rc<RefCell<T>>) -> bool {
+        self.contents()
+            .iter()
+            .any(|content| Rc::ptr_eq(content, &item))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.size < 1
+    }
+
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn clear(&mut self) {
+        SkipList::clear(self);
+    }
+}
+
+// the skip list is safe code, and walking every index is far too slow under Miri
+#[cfg(all(test, not(miri)))]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn list_of(items: std::ops::Range<i32>) -> SkipList<i32> {
+        let mut list = SkipList::new();
+        for i in items {
+            list.add_raw(i);
+        }
+        list
+    }
+
+    fn values(list: &SkipList<i32>) -> Vec<i32> {
+        list.iter_snapshot().map(|item| *item.borrow()).collect()
+    }
+
+    fn out_of_bounds(index: usize, len: usize) -> ListOperationErr {
+        ListOperationErr::IndexOutOfBounds { index, len }
+    }
+
+    /// Lengths covering empty, single element and longer lists, the last with several levels
+    const LENGTHS: [usize; 4] = [0, 1, 6, 40];
+
+    /// Checks that on every level, the widths add up to the positions along the bottom level
+    fn check_widths(list: &SkipList<i32>) {
+        let mut positions = HashMap::new();
+        positions.insert(Rc::as_ptr(&list.head), 0);
+        let mut cur = list.head.borrow().forward[0].clone();
+        while let Some(node) = cur {
+            positions.insert(Rc::as_ptr(&node), positions.len());
+            cur = node.borrow().forward[0].clone();
+        }
+        assert_eq!(positions.len(), list.size + 1);
+
+        for level in 0..MAX_LEVEL {
+            let mut node = list.head.clone();
+            let mut pos = 0;
+            loop {
+                pos += node.borrow().width[level];
+                let next = node.borrow().forward[level].clone();
+                match next {
+                    Some(next) => {
+                        assert_eq!(positions[&Rc::as_ptr(&next)], pos, "level {}", level);
+                        node = next;
+                    }
+                    None => break,
+                }
+            }
+            assert_eq!(pos, list.size + 1, "level {}", level);
+        }
+    }
+
+    #[test]
+    fn get_checks_every_index() {
+        for len in LENGTHS {
+            let list = list_of(0..len as i32);
+            for index in 0..len + 2 {
+                match list.get(index) {
+                    Ok(item) if index < len => assert_eq!(*item.borrow(), index as i32),
+                    result => assert_eq!(result.unwrap_err(), out_of_bounds(index, len)),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn insert_at_checks_every_index() {
+        for len in LENGTHS {
+            for index in 0..len + 2 {
+                let mut list = list_of(0..len as i32);
+                let mut model: Vec<i32> = (0..len as i32).collect();
+                let result = list.insert_at(Rc::new(RefCell::new(-1)), index);
+                if index <= len {
+                    result.unwrap();
+                    model.insert(index, -1);
+                } else {
+                    assert_eq!(result.unwrap_err(), out_of_bounds(index, len));
+                }
+                assert_eq!(values(&list), model);
+                check_widths(&list);
+            }
+        }
+    }
+
+    #[test]
+    fn remove_at_checks_every_index() {
+        for len in LENGTHS {
+            for index in 0..len + 2 {
+                let mut list = list_of(0..len as i32);
+                let mut model: Vec<i32> = (0..len as i32).collect();
+                match list.remove_at(index) {
+                    Ok(item) if index < len => assert_eq!(*item.borrow(), model.remove(index)),
+                    result => assert_eq!(result.unwrap_err(), out_of_bounds(index, len)),
+                }
+                assert_eq!(values(&list), model);
+                check_widths(&list);
+            }
+        }
+    }
+
+    #[test]
+    fn lookups_follow_removals() {
+        let mut list = list_of(0..100);
+        let mut model: Vec<i32> = (0..100).collect();
+        let mut step = 0;
+        while !model.is_empty() {
+            step += 37;
+            let index = step % model.len();
+            if step % 3 == 0 {
+                let item = list.get(index).unwrap();
+                list.remove(item).unwrap();
+            } else {
+                assert_eq!(*list.remove_at(index).unwrap().borrow(), model[index]);
+            }
+            model.remove(index);
+            check_widths(&list);
+            for (index, value) in model.iter().enumerate() {
+                assert_eq!(*list.get(index).unwrap().borrow(), *value);
+            }
+            assert_eq!(list.size(), model.len());
+        }
+        assert!(list.is_empty());
+    }
+}
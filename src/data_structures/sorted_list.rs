@@ -0,0 +1,158 @@
+use super::linked_list::{List, ListOperationErr, SnapshotIterator};
+use super::linked_list2::{LinkedList2, LinkedList2Handles};
+use std::{
+    cell::RefCell,
+    cmp::Ordering,
+    ops::{Bound, RangeBounds},
+    rc::Rc,
+};
+
+/// ### Summary
+/// A `LinkedList2` that keeps its elements in ascending order
+pub struct SortedList<T: Ord + std::fmt::Debug> {
+    list: LinkedList2<T>,
+}
+
+impl<T: Ord + std::fmt::Debug> SortedList<T> {
+    /// Constructs an empty `SortedList<T>`
+    pub const fn new() -> Self {
+        SortedList {
+            list: LinkedList2::new(),
+        }
+    }
+
+    /// add an item at its sorted position, after any equal items
+    /// #### Params
+    /// - `item` - the item to add
+    pub fn insert(&mut self, item: T) {
+        let item = Rc::new(RefCell::new(item));
+        let successor = self
+            .list
+            .handles()
+            .find(|handle| matches!(handle.content(), Ok(c) if *c.borrow() > *item.borrow()));
+
+        match successor {
+            Some(handle) => {
+                let _ = self.list.insert_before(&handle, item);
+            }
+            None => self.list.add(item),
+        }
+    }
+
+    /// removes the first item equal to `item`
+    /// #### Params
+    /// - `item` - the item to be removed
+    pub fn remove(&mut self, item: &T) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        let handle = self
+            .list
+            .handles()
+            .map(|handle| {
+                let ordering = match handle.content() {
+                    Ok(c) => c.borrow().cmp(item),
+                    Err(_) => Ordering::Greater,
+                };
+                (handle, ordering)
+            })
+            .take_while(|(_, ordering)| *ordering != Ordering::Greater)
+            .find(|(_, ordering)| *ordering == Ordering::Equal)
+            .map(|(handle, _)| handle)
+            .ok_or(ListOperationErr::ElementNotFound)?;
+
+        self.list.remove_handle(&handle)
+    }
+
+    /// checks whether an item equal to `item` is in the list
+    /// #### Params
+    /// - `item` - the item to lookup
+    pub fn contains(&self, item: &T) -> bool {
+        self.range((Bound::Included(item), Bound::Included(item)))
+            .next()
+            .is_some()
+    }
+
+    /// #### Params
+    /// - `range` - the bounds of the items to visit
+    /// #### Returns
+    /// an iterator over the items within `range`, in order.
+    /// The walk stops as soon as it is past the upper bound.
+    pub fn range<R: RangeBounds<T>>(&self, range: R) -> SortedListRange<T, R> {
+        SortedListRange {
+            handles: self.list.handles(),
+            range,
+            done: false,
+        }
+    }
+
+    /// #### Returns
+    /// an iterator over the items the list holds right now, in order
+    pub fn iter_snapshot(&self) -> SnapshotIterator<T> {
+        self.list.iter_snapshot()
+    }
+
+    /// #### Returns
+    /// `true` if the list is empty
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// #### Returns
+    /// Number of elements in list
+    pub fn size(&self) -> usize {
+        self.list.size()
+    }
+
+    /// Consumes the sorted list
+    /// #### Returns
+    /// the underlying `LinkedList2<T>`
+    pub fn into_inner(self) -> LinkedList2<T> {
+        self.list
+    }
+}
+
+impl<T: Ord + std::fmt::Debug> Default for SortedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Iterator over the items of a `SortedList` within a range
+pub struct SortedListRange<T, R> {
+    handles: LinkedList2Handles<T>,
+    range: R,
+    done: bool,
+}
+
+impl<T: Ord, R: RangeBounds<T>> Iterator for SortedListRange<T, R> {
+    type Item = Rc<RefCell<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            let content = self.handles.next()?.content().ok()?;
+            let item = content.borrow();
+
+            let below_start = match self.range.start_bound() {
+                Bound::Included(start) => *item < *start,
+                Bound::Excluded(start) => *item <= *start,
+                Bound::Unbounded => false,
+            };
+            if below_start {
+                continue;
+            }
+
+            let past_end = match self.range.end_bound() {
+                Bound::Included(end) => *item > *end,
+                Bound::Excluded(end) => *item >= *end,
+                Bound::Unbounded => false,
+            };
+            if past_end {
+                // everything after this is past the end too
+                self.done = true;
+                return None;
+            }
+
+            drop(item);
+            return Some(content);
+        }
+        None
+    }
+}
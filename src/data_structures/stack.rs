@@ -0,0 +1,86 @@
+use super::linked_list::{LinkedList, List, ListOperationErr, SnapshotIterator};
+use std::{cell::RefCell, rc::Rc};
+
+/// ### Summary
+/// A last-in, first-out stack backed by a `LinkedList`.
+///
+/// The top of the stack is the head of the list, so `push`, `pop` and `peek` are O(1).
+#[derive(Debug, Clone)]
+pub struct Stack<T> {
+    list: LinkedList<T>,
+}
+
+impl<T> Stack<T> {
+    /// Constructs an empty `Stack<T>`
+    pub const fn new() -> Self {
+        Stack {
+            list: LinkedList::new(),
+        }
+    }
+
+    /// add an item on top of the stack
+    /// #### Params
+    /// - `item` - a reference to the item to add
+    pub fn push(&mut self, item: Rc<RefCell<T>>) {
+        self.list.push_front(item);
+    }
+
+    /// add an item on top of the stack
+    /// #### Params
+    /// - `item` - the item to add
+    pub fn push_raw(&mut self, item: T) {
+        self.push(Rc::new(RefCell::new(item)));
+    }
+
+    /// Removes the item on top of the stack
+    pub fn pop(&mut self) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        self.list.shift()
+    }
+
+    /// #### Returns
+    /// a reference to the item on top of the stack
+    pub fn peek(&self) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        self.list
+            .front()
+            .ok_or(ListOperationErr::OperationOnEmptyList)
+    }
+
+    /// #### Returns
+    /// a reference to the item at the bottom of the stack, the first one pushed
+    pub fn bottom(&self) -> Result<Rc<RefCell<T>>, ListOperationErr> {
+        self.list
+            .back()
+            .ok_or(ListOperationErr::OperationOnEmptyList)
+    }
+
+    /// #### Returns
+    /// Number of items on the stack
+    pub fn len(&self) -> usize {
+        self.list.size()
+    }
+
+    /// #### Returns
+    /// `true` if the stack is empty
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// #### Returns
+    /// an iterator over the items on the stack, from the top down
+    pub fn iter_snapshot(&self) -> SnapshotIterator<T> {
+        self.list.iter_snapshot()
+    }
+
+    /// Consumes the stack
+    /// #### Returns
+    /// the backing list, with the top of the stack at its head
+    pub fn into_inner(self) -> LinkedList<T> {
+        self.list
+    }
+}
+
+impl<T> Default for Stack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
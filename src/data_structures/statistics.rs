@@ -0,0 +1,57 @@
+use super::linked_list::ListOperationErr;
+
+/// ### Summary
+/// Numeric element types the list statistics helpers can work with
+pub trait Numeric: Copy {
+    /// #### Returns
+    /// the value as an `f64`
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_numeric {
+    ($($t:ty),*) => {
+        $(
+            impl Numeric for $t {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+/// Arithmetic mean of `values`
+pub(crate) fn mean(values: &[f64]) -> Result<f64, ListOperationErr> {
+    if values.is_empty() {
+        return Err(ListOperationErr::OperationOnEmptyList);
+    }
+    Ok(values.iter().sum::<f64>() / values.len() as f64)
+}
+
+/// Median of `values`, found by selection rather than sorting
+pub(crate) fn median(mut values: Vec<f64>) -> Result<f64, ListOperationErr> {
+    if values.is_empty() {
+        return Err(ListOperationErr::OperationOnEmptyList);
+    }
+
+    let odd = values.len() % 2 == 1;
+    let mid = values.len() / 2;
+    let (lower, upper, _) = values.select_nth_unstable_by(mid, f64::total_cmp);
+    let upper = *upper;
+
+    if odd {
+        Ok(upper)
+    } else {
+        // average the two middle values; the lower one is the greatest value before `mid`
+        let below = lower.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        Ok((below + upper) / 2.0)
+    }
+}
+
+/// Population variance of `values`
+pub(crate) fn variance(values: &[f64]) -> Result<f64, ListOperationErr> {
+    let mean = mean(values)?;
+    Ok(values.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / values.len() as f64)
+}
@@ -0,0 +1,172 @@
+use super::linked_list::{LinkedList, List};
+use std::{cell::RefCell, rc::Rc};
+
+#[derive(Debug)]
+struct TrieNode {
+    byte: u8,
+    terminal: bool,
+    children: LinkedList<TrieNode>,
+}
+
+impl TrieNode {
+    fn new(byte: u8) -> Rc<RefCell<TrieNode>> {
+        Rc::new(RefCell::new(TrieNode {
+            byte,
+            terminal: false,
+            children: LinkedList::new(),
+        }))
+    }
+
+    /// Looks up the child node reached through `byte`
+    fn child(&self, byte: u8) -> Option<Rc<RefCell<TrieNode>>> {
+        self.children
+            .iter()
+            .find(|child| child.borrow().byte == byte)
+            .cloned()
+    }
+}
+
+/// ### Summary
+/// A prefix tree of byte strings (`&str`, `String`, `&[u8]`, ...).
+///
+/// The children of every node are kept in a `LinkedList`, ordered by byte,
+/// so keys are visited in lexicographic order.
+#[derive(Debug)]
+pub struct Trie {
+    root: Rc<RefCell<TrieNode>>,
+    size: usize,
+}
+
+impl Trie {
+    /// Constructs an empty `Trie`
+    pub fn new() -> Self {
+        Trie {
+            root: TrieNode::new(0),
+            size: 0,
+        }
+    }
+
+    /// add a key to the trie
+    /// #### Params
+    /// - `key` - the key to add
+    /// #### Returns
+    /// `false` if the key was already in the trie
+    pub fn insert<K: AsRef<[u8]>>(&mut self, key: K) -> bool {
+        let mut cur = self.root.clone();
+        for &byte in key.as_ref() {
+            let existing = cur.borrow().child(byte);
+            let next = match existing {
+                Some(child) => child,
+                None => {
+                    let child = TrieNode::new(byte);
+                    let mut node = cur.borrow_mut();
+                    node.children.add(child.clone());
+                    node.children.sort_by_cached_key(|n| n.byte);
+                    child
+                }
+            };
+            cur = next;
+        }
+
+        let mut node = cur.borrow_mut();
+        if node.terminal {
+            false
+        } else {
+            node.terminal = true;
+            self.size += 1;
+            true
+        }
+    }
+
+    /// checks whether `key` is in the trie
+    /// #### Params
+    /// - `key` - the key to lookup
+    pub fn contains<K: AsRef<[u8]>>(&self, key: K) -> bool {
+        self.find(key.as_ref())
+            .is_some_and(|node| node.borrow().terminal)
+    }
+
+    /// checks whether any key in the trie starts with `prefix`
+    /// #### Params
+    /// - `prefix` - the prefix to lookup
+    pub fn has_prefix<K: AsRef<[u8]>>(&self, prefix: K) -> bool {
+        self.find(prefix.as_ref()).is_some()
+    }
+
+    /// #### Params
+    /// - `prefix` - the prefix shared by all the visited keys
+    /// #### Returns
+    /// an iterator over the keys starting with `prefix`, in lexicographic order
+    pub fn iter_prefix<K: AsRef<[u8]>>(&self, prefix: K) -> TriePrefixIterator {
+        let prefix = prefix.as_ref();
+        TriePrefixIterator {
+            stack: self
+                .find(prefix)
+                .map(|node| (node, prefix.to_vec()))
+                .into_iter()
+                .collect(),
+        }
+    }
+
+    /// #### Returns
+    /// an iterator over all keys, in lexicographic order
+    pub fn iter(&self) -> TriePrefixIterator {
+        self.iter_prefix([])
+    }
+
+    /// #### Returns
+    /// `true` if the trie is empty
+    pub fn is_empty(&self) -> bool {
+        self.size < 1
+    }
+
+    /// #### Returns
+    /// Number of keys in the trie
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Get the node reached by following `path` from the root
+    fn find(&self, path: &[u8]) -> Option<Rc<RefCell<TrieNode>>> {
+        let mut cur = self.root.clone();
+        for &byte in path {
+            let next = cur.borrow().child(byte)?;
+            cur = next;
+        }
+        Some(cur)
+    }
+}
+
+impl Default for Trie {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Depth-first iterator over the keys of a `Trie` sharing a prefix
+pub struct TriePrefixIterator {
+    stack: Vec<(Rc<RefCell<TrieNode>>, Vec<u8>)>,
+}
+
+impl Iterator for TriePrefixIterator {
+    type Item = Vec<u8>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((node, path)) = self.stack.pop() {
+            let node = node.borrow();
+
+            // push in reverse so the smallest byte is visited first
+            let children: Vec<_> = node.children.clone().into_iter().collect();
+            for child in children.into_iter().rev() {
+                let mut child_path = path.clone();
+                child_path.push(child.borrow().byte);
+                self.stack.push((child, child_path));
+            }
+
+            if node.terminal {
+                return Some(path);
+            }
+        }
+        None
+    }
+}
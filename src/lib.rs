@@ -1,4 +1,48 @@
+/// Emits a `tracing` event for a structural list operation when the `tracing` feature is enabled
+macro_rules! trace_op {
+    ($($field:tt)*) => {
+        #[cfg(feature = "tracing")]
+        tracing::trace!($($field)*);
+    };
+}
+
+/// Counts nodes touched by a list operation when the `instrument` feature is enabled
+macro_rules! touch_nodes {
+    ($nodes:expr) => {
+        #[cfg(feature = "instrument")]
+        crate::data_structures::instrument::touch($nodes as u64);
+    };
+}
+
 pub mod data_structures {
+    pub mod array_list;
+    #[cfg(feature = "async")]
+    pub mod async_queue;
+    pub mod binary_heap;
+    pub mod blocking_queue;
+    pub mod bst;
+    pub mod circular_linked_list;
+    pub mod concurrent_linked_list;
+    pub mod deque;
+    pub mod expiring_list;
+    pub mod frozen_list;
+    #[cfg(feature = "im")]
+    pub mod im_vector;
+    pub mod indexed_linked_list;
+    #[cfg(feature = "instrument")]
+    pub mod instrument;
+    pub mod linked_hash_map;
     pub mod linked_list;
     pub mod linked_list2;
-}
\ No newline at end of file
+    pub mod list_builder;
+    pub mod non_empty_list;
+    pub mod observable_list;
+    pub mod order_maintenance;
+    pub mod owned_linked_list;
+    pub mod queue;
+    pub mod skip_list;
+    pub mod sorted_list;
+    pub mod stack;
+    pub mod statistics;
+    pub mod trie;
+}
@@ -0,0 +1,112 @@
+//! Checks the asymptotic cost of the core list operations by counting touched nodes.
+//!
+//! ```text
+//! cargo test --features instrument --test complexity
+//! ```
+//!
+//! Every check compares the node count of an operation against its expected bound for a
+//! few list sizes, so an accidental O(n) walk (or a whole-list clone) fails loudly.
+#![cfg(feature = "instrument")]
+
+use collections_test::data_structures::{
+    instrument::measure,
+    linked_list::{LinkedList, List},
+    linked_list2::LinkedList2,
+};
+
+const SIZES: [usize; 3] = [16, 1_000, 20_000];
+
+/// slack for the nodes relinked around the one an operation is about
+const RELINKED: u64 = 3;
+
+fn filled<L: List<usize> + Default>(size: usize) -> L {
+    let mut list = L::default();
+    for i in 0..size {
+        list.add_raw(i);
+    }
+    list
+}
+
+/// Indices spread over the whole list, including both ends
+fn probes(size: usize) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..=8).map(|k| (size - 1) * k / 8).collect();
+    indices.dedup();
+    indices
+}
+
+fn check(what: &str, touched: u64, bound: u64) {
+    assert!(
+        touched <= bound,
+        "{} touched {} nodes, expected at most {}",
+        what,
+        touched,
+        bound
+    );
+}
+
+#[test]
+fn add_is_constant() {
+    for &n in &SIZES {
+        let mut list: LinkedList<usize> = filled(n);
+        let mut list2: LinkedList2<usize> = filled(n);
+        check("LinkedList::add", measure(|| list.add_raw(0)).1, RELINKED);
+        check("LinkedList2::add", measure(|| list2.add_raw(0)).1, RELINKED);
+    }
+}
+
+#[test]
+fn shift_and_pop_are_constant() {
+    for &n in &SIZES {
+        let mut list: LinkedList<usize> = filled(n);
+        let mut list2: LinkedList2<usize> = filled(n);
+
+        // shift is O(1), pop is O(1) on the doubly linked list
+        check("LinkedList::shift", measure(|| list.shift()).1, RELINKED);
+        check("LinkedList2::shift", measure(|| list2.shift()).1, RELINKED);
+        check("LinkedList2::pop", measure(|| list2.pop()).1, RELINKED);
+    }
+}
+
+#[test]
+fn get_walks_from_the_closest_end() {
+    for &n in &SIZES {
+        let list: LinkedList<usize> = filled(n);
+        let list2: LinkedList2<usize> = filled(n);
+
+        for i in probes(n) {
+            let nearest_end = i.min(n - i) as u64;
+
+            // get(i) walks from the head on LinkedList, from the closer end on LinkedList2
+            check(
+                &format!("LinkedList::get({}) of {}", i, n),
+                measure(|| list.get(i)).1,
+                i as u64 + 1,
+            );
+            check(
+                &format!("LinkedList2::get({}) of {}", i, n),
+                measure(|| list2.get(i)).1,
+                nearest_end + 1,
+            );
+        }
+    }
+}
+
+#[test]
+fn insert_and_remove_at_walk_from_the_closest_end() {
+    for &n in &SIZES {
+        let mut list2: LinkedList2<usize> = filled(n);
+
+        for i in probes(n) {
+            check(
+                &format!("LinkedList2::insert_at({}) of {}", i, n),
+                measure(|| list2.insert_raw_at(0, i)).1,
+                i.min(n - i) as u64 + 1 + RELINKED,
+            );
+            check(
+                &format!("LinkedList2::remove_at({}) of {}", i, n + 1),
+                measure(|| list2.remove_at(i)).1,
+                i.min(n + 1 - i) as u64 + 1 + RELINKED,
+            );
+        }
+    }
+}